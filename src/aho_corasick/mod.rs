@@ -0,0 +1,127 @@
+use std::collections::VecDeque;
+
+/// A pattern found in a haystack, with byte offsets into it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    /// Index into the patterns the automaton was built from.
+    pub pattern: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Finds every occurrence of a set of patterns in one pass over the haystack,
+/// overlapping ones included.
+///
+/// The automaton works on bytes. Patterns and haystacks are both valid UTF-8, so a
+/// match can only start and end on a character boundary.
+#[derive(Debug, Clone)]
+pub struct AhoCorasick {
+    /// The state after reading a byte, with the failure links already followed.
+    next: Vec<[u32; 256]>,
+    /// The patterns that end in each state, including those of its suffixes.
+    outputs: Vec<Vec<usize>>,
+    lengths: Vec<usize>,
+}
+
+impl AhoCorasick {
+    /// Panics on an empty pattern, which would match everywhere.
+    pub fn new<I, P>(patterns: I) -> AhoCorasick
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<str>,
+    {
+        let mut automaton = AhoCorasick {
+            next: vec![[0; 256]],
+            outputs: vec![vec![]],
+            lengths: vec![],
+        };
+
+        // the trie, with 0 standing for a missing edge as nothing leads back to the root
+        for (pattern, text) in patterns.into_iter().enumerate() {
+            let text = text.as_ref();
+            assert!(!text.is_empty(), "empty pattern {}", pattern);
+
+            let mut state = 0;
+            for byte in text.bytes() {
+                if automaton.next[state][byte as usize] == 0 {
+                    automaton.next.push([0; 256]);
+                    automaton.outputs.push(vec![]);
+                    automaton.next[state][byte as usize] = (automaton.next.len() - 1) as u32;
+                }
+                state = automaton.next[state][byte as usize] as usize;
+            }
+
+            automaton.outputs[state].push(pattern);
+            automaton.lengths.push(text.len());
+        }
+
+        // breadth first, so the failure state of a state is always complete before it
+        let mut fail = vec![0; automaton.next.len()];
+        let mut queue = automaton.next[0]
+            .iter()
+            .filter(|&&child| child != 0)
+            .map(|&child| child as usize)
+            .collect::<VecDeque<_>>();
+
+        while let Some(state) = queue.pop_front() {
+            for byte in 0..256 {
+                let child = automaton.next[state][byte] as usize;
+                let fallback = automaton.next[fail[state]][byte];
+
+                if child == 0 {
+                    automaton.next[state][byte] = fallback;
+                    continue;
+                }
+
+                fail[child] = fallback as usize;
+                let inherited = automaton.outputs[fail[child]].clone();
+                automaton.outputs[child].extend(inherited);
+                queue.push_back(child);
+            }
+        }
+
+        automaton
+    }
+
+    /// Every match, ordered by where it ends.
+    pub fn find_iter<'a>(&'a self, haystack: &'a str) -> impl Iterator<Item = Match> + 'a {
+        haystack
+            .bytes()
+            .enumerate()
+            .scan(0, move |state, (position, byte)| {
+                *state = self.next[*state][byte as usize] as usize;
+                Some((position + 1, *state))
+            })
+            .flat_map(move |(end, state)| {
+                self.outputs[state].iter().map(move |&pattern| Match {
+                    pattern,
+                    start: end - self.lengths[pattern],
+                    end,
+                })
+            })
+    }
+}
+
+#[test]
+fn test_find_iter() {
+    let automaton = AhoCorasick::new(["he", "she", "his", "hers"]);
+    let matches = automaton
+        .find_iter("ushers")
+        .map(|m| (m.pattern, m.start, m.end))
+        .collect::<Vec<_>>();
+
+    assert_eq!(matches, vec![(1, 1, 4), (0, 2, 4), (3, 2, 6)]);
+    assert_eq!(automaton.find_iter("xyz").count(), 0);
+}
+
+#[test]
+fn test_find_iter_unicode() {
+    let automaton = AhoCorasick::new(["fünf", "ü", "η"]);
+    let haystack = "fünfηü";
+    let matches = automaton
+        .find_iter(haystack)
+        .map(|m| &haystack[m.start..m.end])
+        .collect::<Vec<_>>();
+
+    assert_eq!(matches, vec!["ü", "fünf", "η", "ü"]);
+}
@@ -1,4 +1,6 @@
-use std::fmt::Debug;
+use std::{collections::BTreeMap, fmt::Debug};
+
+use num::{CheckedAdd, Zero};
 
 pub struct InspectPrint<I: Iterator> {
     iter: I,
@@ -21,6 +23,58 @@ where
     }
 }
 
+pub struct Paragraphs<I: Iterator> {
+    iter: I,
+}
+
+impl<I: Iterator> Iterator for Paragraphs<I>
+where
+    I::Item: AsRef<str>,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut paragraph = vec![];
+
+        for line in self.iter.by_ref() {
+            if !line.as_ref().is_empty() {
+                paragraph.push(line);
+            } else if !paragraph.is_empty() {
+                break;
+            }
+        }
+
+        if paragraph.is_empty() {
+            None
+        } else {
+            Some(paragraph)
+        }
+    }
+}
+
+pub struct WindowsPairs<I: Iterator> {
+    iter: I,
+    previous: Option<I::Item>,
+}
+
+impl<I: Iterator> Iterator for WindowsPairs<I>
+where
+    I::Item: Clone,
+{
+    type Item = (I::Item, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.previous.is_none() {
+            self.previous = self.iter.next();
+        }
+
+        let current = self.iter.next()?;
+        let previous = self.previous.replace(current.clone())?;
+
+        Some((previous, current))
+    }
+}
+
 pub trait AdventOfCodeIteratorUtils: Iterator {
     fn println(self) -> InspectPrint<Self>
     where
@@ -29,6 +83,110 @@ pub trait AdventOfCodeIteratorUtils: Iterator {
     {
         InspectPrint { iter: self }
     }
+
+    /// Groups lines into the blocks between blank lines. Repeated blank lines don't
+    /// produce empty groups.
+    fn paragraphs(self) -> Paragraphs<Self>
+    where
+        Self: Sized,
+        Self::Item: AsRef<str>,
+    {
+        Paragraphs { iter: self }
+    }
+
+    /// How often each item occurs, in item order. Note that `Itertools` has a
+    /// `counts` returning a `HashMap`, so call this one as
+    /// `AdventOfCodeIteratorUtils::counts(iter)` when both traits are in scope.
+    fn counts(self) -> BTreeMap<Self::Item, usize>
+    where
+        Self: Sized,
+        Self::Item: Ord,
+    {
+        self.fold(BTreeMap::new(), |mut acc, item| {
+            *acc.entry(item).or_default() += 1;
+            acc
+        })
+    }
+
+    /// The smallest and largest item, or `None` if the iterator is empty.
+    fn min_max(self) -> Option<(Self::Item, Self::Item)>
+    where
+        Self: Sized,
+        Self::Item: Ord + Clone,
+    {
+        self.fold(None, |acc, item| match acc {
+            None => Some((item.clone(), item)),
+            Some((min, max)) => Some((min.min(item.clone()), max.max(item))),
+        })
+    }
+
+    /// The sum of all items, or `None` if it overflows along the way.
+    fn sum_checked(mut self) -> Option<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: CheckedAdd + Zero,
+    {
+        self.try_fold(Self::Item::zero(), |acc, item| acc.checked_add(&item))
+    }
+
+    /// Every item together with the one after it, e.g. for difference sequences.
+    fn windows_pairs(self) -> WindowsPairs<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        WindowsPairs {
+            iter: self,
+            previous: None,
+        }
+    }
 }
 
 impl<I: Iterator> AdventOfCodeIteratorUtils for I {}
+
+#[test]
+fn test_paragraphs() {
+    let lines = ["", "#.#", ".#.", "", "", "##", "##", ""];
+
+    assert_eq!(
+        lines.into_iter().paragraphs().collect::<Vec<_>>(),
+        vec![vec!["#.#", ".#."], vec!["##", "##"]]
+    );
+    assert_eq!(Vec::<String>::new().into_iter().paragraphs().next(), None);
+}
+
+#[test]
+fn test_counts() {
+    let counts = "32T3K".chars().counts();
+
+    assert_eq!(
+        counts.into_iter().collect::<Vec<_>>(),
+        vec![('2', 1), ('3', 2), ('K', 1), ('T', 1)]
+    );
+}
+
+#[test]
+fn test_min_max() {
+    assert_eq!([3, -1, 7, 2].into_iter().min_max(), Some((-1, 7)));
+    assert_eq!([5].into_iter().min_max(), Some((5, 5)));
+    assert_eq!(Vec::<i32>::new().into_iter().min_max(), None);
+}
+
+#[test]
+fn test_sum_checked() {
+    assert_eq!([1_u8, 2, 3].into_iter().sum_checked(), Some(6));
+    assert_eq!([200_u8, 50, 10].into_iter().sum_checked(), None);
+    assert_eq!(Vec::<i64>::new().into_iter().sum_checked(), Some(0));
+}
+
+#[test]
+fn test_windows_pairs() {
+    let differences = [0, 3, 6, 10, 15]
+        .into_iter()
+        .windows_pairs()
+        .map(|(a, b)| b - a)
+        .collect::<Vec<_>>();
+
+    assert_eq!(differences, vec![3, 3, 4, 5]);
+    assert_eq!([1].into_iter().windows_pairs().next(), None);
+}
@@ -0,0 +1,121 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// A repeating tail of a simulation. The state at step `start + period` is the same as
+/// the state at step `start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The earliest step whose state is the same as the state at `step`.
+    pub fn equivalent_step(&self, step: usize) -> usize {
+        if step < self.start {
+            step
+        } else {
+            self.start + (step - self.start) % self.period
+        }
+    }
+}
+
+/// Steps the simulation from `initial` until a state repeats. Step 0 is `initial`
+/// itself. Loops forever if the simulation never repeats.
+pub fn detect_cycle<S, F>(initial: S, mut next: F) -> Cycle
+where
+    S: Hash + Eq,
+    F: FnMut(&S) -> S,
+{
+    let mut seen = HashMap::new();
+    let mut state = initial;
+
+    for step in 0.. {
+        let following = next(&state);
+
+        if let Some(start) = seen.insert(state, step) {
+            return Cycle {
+                start,
+                period: step - start,
+            };
+        }
+
+        state = following;
+    }
+
+    unreachable!()
+}
+
+/// The state after `steps` steps, skipping ahead as soon as a cycle shows up.
+pub fn fast_forward<S, F>(initial: S, mut next: F, steps: usize) -> S
+where
+    S: Hash + Eq + Clone,
+    F: FnMut(&S) -> S,
+{
+    let mut seen = HashMap::new();
+    let mut history = vec![];
+    let mut state = initial;
+
+    for step in 0..steps {
+        if let Some(&start) = seen.get(&state) {
+            let cycle = Cycle {
+                start,
+                period: step - start,
+            };
+
+            return history.swap_remove(cycle.equivalent_step(steps));
+        }
+
+        let following = next(&state);
+        seen.insert(state.clone(), step);
+        history.push(state);
+        state = following;
+    }
+
+    state
+}
+
+#[test]
+fn test_detect_cycle() {
+    // 0, 1, 2, 3, 4, 2, 3, 4, ...
+    let cycle = detect_cycle(0, |x| if *x == 4 { 2 } else { x + 1 });
+
+    assert_eq!(
+        cycle,
+        Cycle {
+            start: 2,
+            period: 3
+        }
+    );
+    assert_eq!(cycle.equivalent_step(1), 1);
+    assert_eq!(cycle.equivalent_step(5), 2);
+    assert_eq!(cycle.equivalent_step(10), 4);
+}
+
+#[test]
+fn test_detect_cycle_with_repeating_values() {
+    // the values 1, 2, 1, 2 repeat early, but the state includes a counter that
+    // only wraps every 6 steps
+    let cycle = detect_cycle((0, 1), |(i, value)| ((i + 1) % 6, 3 - value));
+
+    assert_eq!(
+        cycle,
+        Cycle {
+            start: 0,
+            period: 6
+        }
+    );
+}
+
+#[test]
+fn test_fast_forward() {
+    let next = |x: &u64| (x * x + 1) % 255;
+
+    let mut expected = 3;
+    for _ in 0..1000 {
+        expected = next(&expected);
+    }
+
+    assert_eq!(fast_forward(3, next, 1000), expected);
+    assert_eq!(fast_forward(3, next, 0), 3);
+    assert_eq!(fast_forward(3, next, 1), 10);
+}
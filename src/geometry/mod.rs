@@ -0,0 +1,170 @@
+use num::integer::gcd;
+
+use crate::Vec2;
+
+/// A simple (non self-intersecting) polygon on the integer lattice.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polygon {
+    pub vertices: Vec<Vec2<i64>>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Vec2<i64>>) -> Polygon {
+        Polygon { vertices }
+    }
+
+    /// Builds a polygon by walking `(direction, length)` instructions from `start`,
+    /// like the dig plan in day 18.
+    pub fn from_instructions<I>(start: Vec2<i64>, instructions: I) -> Polygon
+    where
+        I: IntoIterator<Item = (Vec2<i64>, i64)>,
+    {
+        let mut position = start;
+
+        let vertices = instructions
+            .into_iter()
+            .map(|(dir, length)| {
+                position += dir * length;
+                position
+            })
+            .collect();
+
+        Polygon { vertices }
+    }
+
+    fn edges(&self) -> impl Iterator<Item = (&Vec2<i64>, &Vec2<i64>)> {
+        self.vertices
+            .iter()
+            .zip(self.vertices.iter().cycle().skip(1))
+    }
+
+    /// Twice the enclosed area, using the shoelace formula. Always an integer for
+    /// lattice polygons.
+    pub fn doubled_area(&self) -> i64 {
+        self.edges()
+            .map(|(a, b)| a.0 * b.1 - b.0 * a.1)
+            .sum::<i64>()
+            .abs()
+    }
+
+    pub fn area(&self) -> f64 {
+        self.doubled_area() as f64 / 2.0
+    }
+
+    /// Number of lattice points lying on the edges of the polygon.
+    pub fn boundary_points(&self) -> i64 {
+        self.edges()
+            .map(|(a, b)| gcd((b.0 - a.0).abs(), (b.1 - a.1).abs()))
+            .sum()
+    }
+
+    /// Number of lattice points strictly inside the polygon.
+    pub fn interior_points(&self) -> i64 {
+        // pick's theorem: A = i + b/2 - 1
+        // so; i = (2A - b + 2) / 2
+        (self.doubled_area() - self.boundary_points() + 2) / 2
+    }
+
+    /// Even-odd rule point-in-polygon test. Points on the boundary count as inside.
+    pub fn contains(&self, pos: &Vec2<i64>) -> bool {
+        let Vec2(x, y) = *pos;
+        let mut inside = false;
+
+        for (a, b) in self.edges() {
+            let cross = (b.0 - a.0) * (y - a.1) - (b.1 - a.1) * (x - a.0);
+
+            if cross == 0
+                && (a.0.min(b.0)..=a.0.max(b.0)).contains(&x)
+                && (a.1.min(b.1)..=a.1.max(b.1)).contains(&y)
+            {
+                // point is on boundary
+                return true;
+            }
+
+            if (a.1 > y) != (b.1 > y) && (cross > 0) == (b.1 > a.1) {
+                inside = !inside;
+            }
+        }
+
+        inside
+    }
+}
+
+#[test]
+fn test_square() {
+    let square = Polygon::new(vec![Vec2(0, 0), Vec2(4, 0), Vec2(4, 4), Vec2(0, 4)]);
+
+    assert_eq!(square.doubled_area(), 32);
+    assert_eq!(square.area(), 16.0);
+    assert_eq!(square.boundary_points(), 16);
+    assert_eq!(square.interior_points(), 9);
+}
+
+#[test]
+fn test_triangle() {
+    let triangle = Polygon::new(vec![Vec2(0, 0), Vec2(3, 0), Vec2(0, 3)]);
+
+    assert_eq!(triangle.area(), 4.5);
+    assert_eq!(triangle.boundary_points(), 9);
+    assert_eq!(triangle.interior_points(), 1);
+}
+
+#[test]
+fn test_from_instructions() {
+    let polygon = Polygon::from_instructions(
+        Vec2(0, 0),
+        [
+            (Vec2(1, 0), 6),
+            (Vec2(0, 1), 5),
+            (Vec2(-1, 0), 2),
+            (Vec2(0, 1), 2),
+            (Vec2(-1, 0), 4),
+            (Vec2(0, -1), 7),
+        ],
+    );
+
+    assert_eq!(polygon.vertices.last(), Some(&Vec2(0, 0)));
+    assert_eq!(polygon.doubled_area(), 2 * (6 * 5 + 4 * 2));
+    assert_eq!(polygon.boundary_points(), 26);
+    assert_eq!(polygon.interior_points() + polygon.boundary_points(), 52);
+}
+
+#[test]
+fn test_contains() {
+    // U shape with the notch between x=1..3 from the top
+    let polygon = Polygon::new(vec![
+        Vec2(0, 0),
+        Vec2(1, 0),
+        Vec2(1, 3),
+        Vec2(3, 3),
+        Vec2(3, 0),
+        Vec2(4, 0),
+        Vec2(4, 4),
+        Vec2(0, 4),
+    ]);
+
+    assert!(polygon.contains(&Vec2(0, 0)));
+    assert!(polygon.contains(&Vec2(2, 3)));
+    assert!(polygon.contains(&Vec2(2, 4)));
+    assert!(polygon.contains(&Vec2(3, 2)));
+    assert!(polygon.contains(&Vec2(4, 2)));
+    assert!(!polygon.contains(&Vec2(2, 1)));
+    assert!(!polygon.contains(&Vec2(5, 2)));
+    assert!(!polygon.contains(&Vec2(2, -1)));
+    assert!(!polygon.contains(&Vec2(2, 5)));
+}
+
+#[test]
+fn test_contains_agrees_with_pick() {
+    let polygon = Polygon::new(vec![Vec2(0, 0), Vec2(5, 1), Vec2(3, 4), Vec2(1, 3)]);
+
+    let inside = (-1..=6)
+        .flat_map(|x| (-1..=6).map(move |y| Vec2(x, y)))
+        .filter(|pos| polygon.contains(pos))
+        .count() as i64;
+
+    assert_eq!(
+        inside,
+        polygon.interior_points() + polygon.boundary_points()
+    );
+}
@@ -0,0 +1,492 @@
+use std::{
+    collections::{BinaryHeap, HashMap, VecDeque},
+    fmt::Write,
+};
+
+use itertools::Itertools;
+
+/// A graph over named nodes. Names are interned, so nodes are referred to by their
+/// index everywhere except when adding edges.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Graph {
+    directed: bool,
+    names: Vec<String>,
+    ids: HashMap<String, usize>,
+    edges: Vec<Vec<usize>>,
+}
+
+impl Graph {
+    pub fn directed() -> Graph {
+        Graph {
+            directed: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn undirected() -> Graph {
+        Graph::default()
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    /// The index of the node called `name`, adding it if it's new.
+    pub fn node(&mut self, name: &str) -> usize {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+
+        let id = self.names.len();
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        self.edges.push(vec![]);
+        id
+    }
+
+    pub fn id(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: usize) -> &str {
+        &self.names[id]
+    }
+
+    /// Adds an edge between two nodes, adding the nodes too if they're new.
+    pub fn add_edge(&mut self, from: &str, to: &str) -> (usize, usize) {
+        let (from, to) = (self.node(from), self.node(to));
+
+        self.edges[from].push(to);
+        if !self.directed && from != to {
+            self.edges[to].push(from);
+        }
+
+        (from, to)
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn neighbours(&self, id: usize) -> &[usize] {
+        &self.edges[id]
+    }
+
+    /// Every edge once, also for undirected graphs.
+    pub fn edges(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.edges
+            .iter()
+            .enumerate()
+            .flat_map(move |(from, targets)| {
+                targets
+                    .iter()
+                    .filter(move |&&to| self.directed || from <= to)
+                    .map(move |&to| (from, to))
+            })
+    }
+
+    /// The same graph with every edge pointing the other way.
+    pub fn reversed(&self) -> Graph {
+        let mut graph = Graph {
+            directed: self.directed,
+            names: self.names.clone(),
+            ids: self.ids.clone(),
+            edges: vec![vec![]; self.len()],
+        };
+
+        for (from, to) in self.edges() {
+            graph.edges[to].push(from);
+            if !self.directed && from != to {
+                graph.edges[from].push(to);
+            }
+        }
+
+        graph
+    }
+
+    /// Whether each node can be reached from `start`, `start` itself included.
+    pub fn reachable(&self, start: usize) -> Vec<bool> {
+        let mut seen = vec![false; self.len()];
+        let mut queue = VecDeque::from([start]);
+        seen[start] = true;
+
+        while let Some(node) = queue.pop_front() {
+            for &next in &self.edges[node] {
+                if !seen[next] {
+                    seen[next] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        seen
+    }
+
+    /// Orders the nodes so every edge points forwards, or `None` if there's a cycle.
+    pub fn topological_sort(&self) -> Option<Vec<usize>> {
+        let mut incoming = vec![0; self.len()];
+        for (_, to) in self.edges() {
+            incoming[to] += 1;
+        }
+
+        let mut queue = (0..self.len())
+            .filter(|&node| incoming[node] == 0)
+            .collect::<VecDeque<_>>();
+        let mut order = vec![];
+
+        while let Some(node) = queue.pop_front() {
+            order.push(node);
+
+            for &next in &self.edges[node] {
+                incoming[next] -= 1;
+                if incoming[next] == 0 {
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        (order.len() == self.len()).then_some(order)
+    }
+
+    /// Groups of nodes that can all reach each other, using Tarjan's algorithm. The
+    /// components come out in reverse topological order.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<usize>> {
+        const UNVISITED: usize = usize::MAX;
+
+        let mut index = vec![UNVISITED; self.len()];
+        let mut low_link = vec![0; self.len()];
+        let mut on_stack = vec![false; self.len()];
+        let mut stack = vec![];
+        let mut components = vec![];
+        let mut counter = 0;
+
+        for root in 0..self.len() {
+            if index[root] != UNVISITED {
+                continue;
+            }
+
+            // an explicit call stack of (node, next edge to look at)
+            let mut calls = vec![(root, 0)];
+            index[root] = counter;
+            low_link[root] = counter;
+            counter += 1;
+            stack.push(root);
+            on_stack[root] = true;
+
+            while let Some(&mut (node, ref mut edge)) = calls.last_mut() {
+                if let Some(&next) = self.edges[node].get(*edge) {
+                    *edge += 1;
+
+                    if index[next] == UNVISITED {
+                        index[next] = counter;
+                        low_link[next] = counter;
+                        counter += 1;
+                        stack.push(next);
+                        on_stack[next] = true;
+                        calls.push((next, 0));
+                    } else if on_stack[next] {
+                        low_link[node] = low_link[node].min(index[next]);
+                    }
+
+                    continue;
+                }
+
+                calls.pop();
+
+                if let Some(&(parent, _)) = calls.last() {
+                    low_link[parent] = low_link[parent].min(low_link[node]);
+                }
+
+                if low_link[node] == index[node] {
+                    let mut component = vec![];
+
+                    while let Some(member) = stack.pop() {
+                        on_stack[member] = false;
+                        component.push(member);
+
+                        if member == node {
+                            break;
+                        }
+                    }
+
+                    components.push(component);
+                }
+            }
+        }
+
+        components
+    }
+
+    /// The lightest cut of an undirected graph where every edge weighs 1, as the
+    /// number of edges cut and the nodes on one side. See [`min_cut`].
+    pub fn min_cut(&self) -> Option<(i64, Vec<usize>)> {
+        let edges = self.edges().map(|(a, b)| (a, b, 1)).collect_vec();
+
+        min_cut(self.len(), &edges)
+    }
+
+    /// The graph in Graphviz DOT format, e.g. for `dot -Tsvg`.
+    pub fn to_dot(&self) -> String {
+        let (kind, arrow) = if self.directed {
+            ("digraph", "->")
+        } else {
+            ("graph", "--")
+        };
+
+        let mut dot = format!("{} {{\n", kind);
+
+        for name in &self.names {
+            writeln!(dot, "    {:?};", name).unwrap();
+        }
+
+        for (from, to) in self.edges() {
+            writeln!(
+                dot,
+                "    {:?} {} {:?};",
+                self.names[from], arrow, self.names[to]
+            )
+            .unwrap();
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// A few lines about the shape of the graph, to read next to [`Graph::to_dot`]:
+    /// its size, the nodes without outgoing edges and the nodes that lie on a cycle.
+    /// For an undirected graph the last two are its connected components.
+    pub fn summary(&self) -> String {
+        let names = |ids: &[usize]| ids.iter().map(|&id| self.name(id)).sorted().join(", ");
+        let components = self.strongly_connected_components();
+
+        let mut summary = format!("{} nodes, {} edges\n", self.len(), self.edges().count());
+
+        if !self.directed {
+            writeln!(summary, "connected components: {}", components.len()).unwrap();
+            return summary;
+        }
+
+        let sinks = (0..self.len())
+            .filter(|&id| self.neighbours(id).is_empty())
+            .collect_vec();
+        if !sinks.is_empty() {
+            writeln!(summary, "without outgoing edges: {}", names(&sinks)).unwrap();
+        }
+
+        let cycles = components
+            .iter()
+            .filter(|component| {
+                component.len() > 1 || self.neighbours(component[0]).contains(&component[0])
+            })
+            .collect_vec();
+        if cycles.is_empty() {
+            summary.push_str("acyclic\n");
+        }
+        for component in cycles {
+            writeln!(summary, "cycle through {}", names(component)).unwrap();
+        }
+
+        summary
+    }
+}
+
+/// The lightest set of edges whose removal splits an undirected graph in two, using
+/// the Stoer–Wagner algorithm. Nodes are `0..node_count` and edges are
+/// `(a, b, weight)`. Returns the weight of the cut and the nodes on one side of it,
+/// or `None` if there are fewer than two nodes.
+pub fn min_cut(node_count: usize, edges: &[(usize, usize, i64)]) -> Option<(i64, Vec<usize>)> {
+    let mut neighbours: Vec<HashMap<usize, i64>> = vec![HashMap::new(); node_count];
+    for &(a, b, weight) in edges {
+        if a != b {
+            *neighbours[a].entry(b).or_default() += weight;
+            *neighbours[b].entry(a).or_default() += weight;
+        }
+    }
+
+    // every node stands for the original nodes merged into it
+    let mut merged = (0..node_count).map(|node| vec![node]).collect::<Vec<_>>();
+    let mut active = (0..node_count).collect::<Vec<_>>();
+    let mut best: Option<(i64, Vec<usize>)> = None;
+
+    while active.len() > 1 {
+        // add nodes one by one, always the one most tightly connected to those
+        // added so far
+        let mut connection = HashMap::new();
+        let mut added = vec![false; node_count];
+        let mut queue = BinaryHeap::from([(0, active[0])]);
+        let mut order = vec![];
+
+        while let Some((weight, node)) = queue.pop() {
+            if added[node] || weight != connection.get(&node).copied().unwrap_or(0) {
+                continue;
+            }
+
+            added[node] = true;
+            order.push((node, weight));
+
+            for (&next, &edge) in &neighbours[node] {
+                if !added[next] {
+                    let total = connection.entry(next).or_insert(0);
+                    *total += edge;
+                    queue.push((*total, next));
+                }
+            }
+        }
+
+        // a disconnected graph has a cut of weight 0
+        if order.len() < active.len() {
+            let side = order.iter().flat_map(|(node, _)| merged[*node].clone());
+            return Some((0, side.collect()));
+        }
+
+        let (last, cut) = order[order.len() - 1];
+        let (before, _) = order[order.len() - 2];
+
+        if best.as_ref().is_none_or(|(weight, _)| cut < *weight) {
+            best = Some((cut, merged[last].clone()));
+        }
+
+        // merge the last node into the one before it
+        for (next, edge) in std::mem::take(&mut neighbours[last]) {
+            neighbours[next].remove(&last);
+
+            if next != before {
+                *neighbours[before].entry(next).or_default() += edge;
+                *neighbours[next].entry(before).or_default() += edge;
+            }
+        }
+
+        let nodes = std::mem::take(&mut merged[last]);
+        merged[before].extend(nodes);
+        active.retain(|node| *node != last);
+    }
+
+    best
+}
+
+#[test]
+fn test_min_cut() {
+    // two triangles joined by a single edge
+    let edges = [
+        (0, 1, 1),
+        (1, 2, 1),
+        (2, 0, 1),
+        (3, 4, 1),
+        (4, 5, 1),
+        (5, 3, 1),
+        (2, 3, 1),
+    ];
+
+    let (weight, mut side) = min_cut(6, &edges).unwrap();
+    side.sort();
+
+    assert_eq!(weight, 1);
+    assert!(side == vec![0, 1, 2] || side == vec![3, 4, 5]);
+}
+
+#[test]
+fn test_min_cut_weighted() {
+    // the example graph from the Stoer–Wagner paper, with a minimum cut of 4
+    let edges = [
+        (0, 1, 2),
+        (0, 4, 3),
+        (1, 2, 3),
+        (1, 4, 2),
+        (1, 5, 2),
+        (2, 3, 4),
+        (2, 6, 2),
+        (3, 6, 2),
+        (3, 7, 2),
+        (4, 5, 3),
+        (5, 6, 1),
+        (6, 7, 3),
+    ];
+
+    let (weight, mut side) = min_cut(8, &edges).unwrap();
+    side.sort();
+
+    assert_eq!(weight, 4);
+    assert!(side == vec![2, 3, 6, 7] || side == vec![0, 1, 4, 5]);
+
+    assert_eq!(min_cut(1, &[]), None);
+    assert_eq!(min_cut(2, &[]).map(|(weight, _)| weight), Some(0));
+}
+
+#[test]
+fn test_graph() {
+    let mut graph = Graph::directed();
+    graph.add_edge("a", "b");
+    graph.add_edge("b", "c");
+    graph.add_edge("a", "c");
+
+    assert_eq!(graph.len(), 3);
+    assert_eq!(graph.id("b"), Some(1));
+    assert_eq!(graph.name(2), "c");
+    assert_eq!(graph.neighbours(0), &[1, 2]);
+    assert_eq!(graph.reachable(1), vec![false, true, true]);
+    assert_eq!(graph.reversed().reachable(1), vec![true, true, false]);
+    assert_eq!(graph.topological_sort(), Some(vec![0, 1, 2]));
+
+    graph.add_edge("c", "a");
+    assert_eq!(graph.topological_sort(), None);
+}
+
+#[test]
+fn test_strongly_connected_components() {
+    let mut graph = Graph::directed();
+    for (from, to) in [
+        ("a", "b"),
+        ("b", "c"),
+        ("c", "a"),
+        ("c", "d"),
+        ("d", "e"),
+        ("e", "d"),
+        ("e", "f"),
+    ] {
+        graph.add_edge(from, to);
+    }
+
+    let components = graph
+        .strongly_connected_components()
+        .into_iter()
+        .map(|component| {
+            component
+                .into_iter()
+                .map(|id| graph.name(id))
+                .sorted()
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        components,
+        vec![vec!["f"], vec!["d", "e"], vec!["a", "b", "c"]]
+    );
+    assert_eq!(
+        graph.summary(),
+        "6 nodes, 7 edges\nwithout outgoing edges: f\ncycle through d, e\ncycle through a, b, c\n"
+    );
+}
+
+#[test]
+fn test_undirected() {
+    let mut graph = Graph::undirected();
+    graph.add_edge("a", "b");
+    graph.add_edge("b", "c");
+
+    assert_eq!(graph.reachable(2), vec![true, true, true]);
+    assert_eq!(graph.edges().collect::<Vec<_>>(), vec![(0, 1), (1, 2)]);
+    assert_eq!(graph.min_cut().map(|(weight, _)| weight), Some(1));
+    assert_eq!(
+        graph.summary(),
+        "3 nodes, 2 edges\nconnected components: 1\n"
+    );
+    assert_eq!(
+        graph.to_dot(),
+        "graph {\n    \"a\";\n    \"b\";\n    \"c\";\n    \"a\" -- \"b\";\n    \"b\" -- \"c\";\n}\n"
+    );
+}
@@ -3,7 +3,6 @@ use std::{
     cmp::Reverse,
     collections::{BinaryHeap, HashMap, HashSet},
     fmt::Debug,
-    io::{BufRead, Lines},
     num::TryFromIntError,
     ops::Add,
 };
@@ -12,25 +11,7 @@ use itertools::Itertools;
 
 use crate::{aoc_iteratorutils::AdventOfCodeIteratorUtils, Vec2};
 
-pub trait ToGrid {
-    fn to_grid(self) -> Grid;
-}
-
-impl<B: BufRead> ToGrid for Lines<B> {
-    fn to_grid(self) -> Grid
-    where
-        Self: Sized,
-    {
-        let input: String = self
-            .map(|line| line.unwrap() + "\n")
-            .flat_map(|line| line.chars().collect_vec())
-            .collect();
-
-        Grid::new(&input)
-    }
-}
-
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Grid {
     pub data: Vec<char>,
     pub width: usize,
@@ -150,9 +131,9 @@ impl<T: GridBounds<VecT>, VecT: PartialEq + Copy + Add<Output = VecT>> GridWalkW
 
             for direction in directions
                 .iter()
-                .filter(|&&dir| self.in_bounds(&(pos.0 + dir.0, pos.1 + dir.1)))
+                .filter(|&&dir| self.in_bounds(&(pos + dir)))
             {
-                let value = ((pos.0 + direction.0, pos.1 + direction.1), *direction);
+                let value = (pos + *direction, *direction);
 
                 if visited.iter().find(|vis| **vis == value) == None {
                     next_directions.push((value));
@@ -321,9 +302,6 @@ impl GridFindPosition for Grid {
         let x = (start_pos % self.width).try_into()?;
         let y = (start_pos / self.width).try_into()?;
 
-        Ok((
-            x,
-            y
-        ))
+        Ok(Vec2(x, y))
     }
 }
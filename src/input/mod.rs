@@ -0,0 +1,303 @@
+use std::{
+    env, fmt, fs,
+    io::{self, Read, Write},
+    net::TcpStream,
+    path::PathBuf,
+    process::{Command, Stdio},
+};
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+#[derive(Debug)]
+pub enum InputError {
+    /// Not in the cache and no session token to fetch it with.
+    Missing {
+        path: PathBuf,
+        year: usize,
+        day: usize,
+    },
+    Fetch(String),
+    Io(io::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Missing { path, year, day } => write!(
+                f,
+                "{} is missing. Download it from {}/{}/day/{}/input and save it there, \
+                 or put your adventofcode.com session cookie in the AOC_SESSION \
+                 environment variable or a .session file to fetch it automatically.",
+                path.display(),
+                DEFAULT_BASE_URL,
+                year,
+                day
+            ),
+            InputError::Fetch(message) => write!(f, "fetching the input failed: {}", message),
+            InputError::Io(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+impl From<io::Error> for InputError {
+    fn from(error: io::Error) -> Self {
+        InputError::Io(error)
+    }
+}
+
+/// Something that can download a puzzle input.
+pub trait Fetch {
+    fn fetch(&self, year: usize, day: usize, session: &str) -> Result<String, InputError>;
+}
+
+/// Plain HTTP, enough for a local server standing in for the real site.
+pub struct HttpFetch {
+    pub base_url: String,
+}
+
+impl Fetch for HttpFetch {
+    fn fetch(&self, year: usize, day: usize, session: &str) -> Result<String, InputError> {
+        let address = self
+            .base_url
+            .strip_prefix("http://")
+            .ok_or_else(|| InputError::Fetch(format!("not an http url: {}", self.base_url)))?;
+        let (host, prefix) = address.split_once('/').unwrap_or((address, ""));
+        let prefix = prefix.trim_end_matches('/');
+        let path = if prefix.is_empty() {
+            format!("/{}/day/{}/input", year, day)
+        } else {
+            format!("/{}/{}/day/{}/input", prefix, year, day)
+        };
+
+        let mut stream = TcpStream::connect(host)?;
+        write!(
+            stream,
+            "GET {} HTTP/1.0\r\nHost: {}\r\nCookie: session={}\r\n\r\n",
+            path, host, session
+        )?;
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response)?;
+
+        let (head, body) = response
+            .split_once("\r\n\r\n")
+            .ok_or_else(|| InputError::Fetch("malformed response".to_string()))?;
+        let status = head.lines().next().unwrap_or_default();
+
+        if status.split_whitespace().nth(1) != Some("200") {
+            return Err(InputError::Fetch(format!("{} returned {}", path, status)));
+        }
+
+        Ok(body.to_string())
+    }
+}
+
+/// HTTPS through the `curl` command, for the real site.
+pub struct CurlFetch {
+    pub base_url: String,
+}
+
+impl Fetch for CurlFetch {
+    fn fetch(&self, year: usize, day: usize, session: &str) -> Result<String, InputError> {
+        let url = format!(
+            "{}/{}/day/{}/input",
+            self.base_url.trim_end_matches('/'),
+            year,
+            day
+        );
+
+        // the cookie goes in through a config on stdin, so it doesn't show up in `ps`
+        let mut child = Command::new("curl")
+            .args(["--fail", "--silent", "--show-error", "--config", "-"])
+            .arg(&url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        let session = session.replace('\\', "\\\\").replace('"', "\\\"");
+        if let Some(mut stdin) = child.stdin.take() {
+            writeln!(stdin, "cookie = \"session={}\"", session)?;
+        }
+
+        let output = child.wait_with_output()?;
+
+        if !output.status.success() {
+            return Err(InputError::Fetch(format!(
+                "{}: {}",
+                url,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+
+        String::from_utf8(output.stdout).map_err(|error| InputError::Fetch(error.to_string()))
+    }
+}
+
+/// Puzzle inputs, read from a local cache directory and fetched on a cache miss.
+pub struct Inputs {
+    pub dir: PathBuf,
+    pub session: Option<String>,
+    pub backend: Box<dyn Fetch>,
+}
+
+impl Inputs {
+    /// Configured by `AOC_SESSION` (or a `.session` file) and `AOC_BASE_URL`.
+    pub fn from_env() -> Inputs {
+        let session = env::var("AOC_SESSION")
+            .ok()
+            .or_else(|| fs::read_to_string(".session").ok())
+            .map(|session| session.trim().to_string())
+            .filter(|session| !session.is_empty());
+
+        let base_url = env::var("AOC_BASE_URL").unwrap_or(DEFAULT_BASE_URL.to_string());
+        let backend: Box<dyn Fetch> = if base_url.starts_with("http://") {
+            Box::new(HttpFetch { base_url })
+        } else {
+            Box::new(CurlFetch { base_url })
+        };
+
+        Inputs {
+            dir: PathBuf::from("inputs"),
+            session,
+            backend,
+        }
+    }
+
+    /// Inputs are kept per year, e.g. `inputs/2023/day17.txt`.
+    pub fn path(&self, year: usize, day: usize) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("day{}.txt", day))
+    }
+
+    /// The answers accepted for a year's inputs, see [`crate::report::Ledger`].
+    pub fn ledger(&self, year: usize) -> PathBuf {
+        self.dir.join(year.to_string()).join("answers.txt")
+    }
+
+    /// Makes sure the input for `day` is in the cache and returns its path. An empty
+    /// file, like the one `aoc new` creates, counts as missing.
+    pub fn ensure(&self, year: usize, day: usize) -> Result<PathBuf, InputError> {
+        let path = self.path(year, day);
+
+        if fs::metadata(&path).is_ok_and(|metadata| metadata.len() > 0) {
+            return Ok(path);
+        }
+
+        let Some(session) = &self.session else {
+            return Err(InputError::Missing { path, year, day });
+        };
+
+        let input = self.backend.fetch(year, day, session)?;
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&path, input)?;
+
+        Ok(path)
+    }
+
+    pub fn get(&self, year: usize, day: usize) -> Result<String, InputError> {
+        Ok(fs::read_to_string(self.ensure(year, day)?)?)
+    }
+}
+
+#[cfg(test)]
+fn stub_server(
+    status: &'static str,
+    body: &'static str,
+) -> (String, std::thread::JoinHandle<String>) {
+    use std::{io::BufRead, io::BufReader, net::TcpListener, thread};
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+
+    let handle = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+
+        // read the request up to the blank line
+        let mut request = String::new();
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            if line.trim().is_empty() {
+                break;
+            }
+            request.push_str(&line);
+        }
+
+        write!(stream, "HTTP/1.0 {}\r\n\r\n{}", status, body).unwrap();
+        request
+    });
+
+    (format!("http://{}", address), handle)
+}
+
+#[cfg(test)]
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn test_fetch_and_cache() {
+    let (base_url, server) = stub_server("200 OK", "1 2 3\n");
+    let inputs = Inputs {
+        dir: temp_dir("fetch"),
+        session: Some("secret".to_string()),
+        backend: Box::new(HttpFetch { base_url }),
+    };
+
+    assert_eq!(inputs.get(2023, 7).unwrap(), "1 2 3\n");
+
+    let request = server.join().unwrap();
+    assert!(request.starts_with("GET /2023/day/7/input HTTP/1.0"));
+    assert!(request.contains("Cookie: session=secret"));
+    assert!(inputs.path(2023, 7).ends_with("2023/day7.txt"));
+    assert!(inputs.path(2023, 7).exists());
+
+    // the server is gone, so this has to come from the cache
+    assert_eq!(inputs.get(2023, 7).unwrap(), "1 2 3\n");
+
+    fs::remove_dir_all(&inputs.dir).unwrap();
+}
+
+#[test]
+fn test_fetch_error() {
+    let (base_url, server) = stub_server("400 Bad Request", "Please log in");
+    let inputs = Inputs {
+        dir: temp_dir("error"),
+        session: Some("expired".to_string()),
+        backend: Box::new(HttpFetch { base_url }),
+    };
+
+    let error = inputs.get(2022, 3).unwrap_err();
+    server.join().unwrap();
+
+    assert!(matches!(error, InputError::Fetch(_)));
+    assert!(!inputs.path(2022, 3).exists());
+}
+
+#[test]
+fn test_missing_without_session() {
+    let inputs = Inputs {
+        dir: temp_dir("missing"),
+        session: None,
+        backend: Box::new(HttpFetch {
+            base_url: "http://127.0.0.1:1".to_string(),
+        }),
+    };
+
+    let error = inputs.get(2023, 12).unwrap_err();
+    let message = error.to_string();
+
+    assert!(message.contains("day12.txt is missing"));
+    assert!(message.contains("https://adventofcode.com/2023/day/12/input"));
+    assert!(message.contains("AOC_SESSION"));
+}
@@ -0,0 +1,323 @@
+use std::cmp::Ordering;
+
+/// A half-open range of integers, `start..end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub fn new(start: i64, end: i64) -> Interval {
+        Interval { start, end }
+    }
+
+    pub fn len(&self) -> i64 {
+        (self.end - self.start).max(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        let interval = Interval::new(self.start.max(other.start), self.end.min(other.end));
+
+        (!interval.is_empty()).then_some(interval)
+    }
+
+    /// Splits into the parts below `at` and from `at` upwards. Either side is `None`
+    /// when it would be empty.
+    pub fn split_at(&self, at: i64) -> (Option<Interval>, Option<Interval>) {
+        let lower = Interval::new(self.start, self.end.min(at));
+        let upper = Interval::new(self.start.max(at), self.end);
+
+        (
+            (!lower.is_empty()).then_some(lower),
+            (!upper.is_empty()).then_some(upper),
+        )
+    }
+
+    pub fn shift(&self, offset: i64) -> Interval {
+        Interval::new(self.start + offset, self.end + offset)
+    }
+}
+
+/// A set of integers stored as sorted, disjoint and non-adjacent intervals.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> IntervalSet {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+
+        // find every interval that overlaps or touches the new one and merge them
+        let first = self.intervals.partition_point(|x| x.end < interval.start);
+        let last = self.intervals.partition_point(|x| x.start <= interval.end);
+
+        let merged = self.intervals[first..last].iter().fold(interval, |acc, x| {
+            Interval::new(acc.start.min(x.start), acc.end.max(x.end))
+        });
+
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Interval> {
+        self.intervals.iter()
+    }
+
+    /// Number of integers in the set.
+    pub fn len(&self) -> i64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.intervals
+            .binary_search_by(|x| {
+                if x.end <= value {
+                    Ordering::Less
+                } else if x.start > value {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            })
+            .is_ok()
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.intervals.first().map(|x| x.start)
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.intervals.last().map(|x| x.end - 1)
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut set = self.clone();
+        for interval in other.iter() {
+            set.insert(*interval);
+        }
+        set
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        self.iter()
+            .flat_map(|a| other.iter().filter_map(|b| a.intersection(b)))
+            .collect()
+    }
+
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut remaining = self.intervals.clone();
+
+        for hole in other.iter() {
+            remaining = remaining
+                .into_iter()
+                .flat_map(|x| {
+                    let (lower, _) = x.split_at(hole.start);
+                    let (_, upper) = x.split_at(hole.end);
+                    lower.into_iter().chain(upper)
+                })
+                .collect();
+        }
+
+        remaining.into_iter().collect()
+    }
+
+    /// Splits into the values below `at` and the values from `at` upwards.
+    pub fn split_at(&self, at: i64) -> (IntervalSet, IntervalSet) {
+        let mut lower = IntervalSet::new();
+        let mut upper = IntervalSet::new();
+
+        for interval in self.iter() {
+            let (below, above) = interval.split_at(at);
+            lower.extend(below);
+            upper.extend(above);
+        }
+
+        (lower, upper)
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Interval> for IntervalSet {
+    fn extend<I: IntoIterator<Item = Interval>>(&mut self, iter: I) {
+        for interval in iter {
+            self.insert(interval);
+        }
+    }
+}
+
+/// A piecewise map which shifts every value inside a source interval by that
+/// interval's offset. Values outside all source intervals map to themselves.
+#[derive(Debug, Clone, Default)]
+pub struct IntervalMap {
+    mappings: Vec<(Interval, i64)>,
+}
+
+impl IntervalMap {
+    pub fn new() -> IntervalMap {
+        Self::default()
+    }
+
+    /// Source intervals are expected not to overlap; the first inserted one wins if
+    /// they do.
+    pub fn insert(&mut self, source: Interval, offset: i64) {
+        self.mappings.push((source, offset));
+    }
+
+    pub fn map(&self, value: i64) -> i64 {
+        self.mappings
+            .iter()
+            .find(|(source, _)| source.contains(value))
+            .map_or(value, |(_, offset)| value + offset)
+    }
+
+    /// Maps every value of `set` at once by splitting it along the source intervals.
+    pub fn map_set(&self, set: &IntervalSet) -> IntervalSet {
+        let mut unmapped = set.clone();
+        let mut mapped = IntervalSet::new();
+
+        for (source, offset) in &self.mappings {
+            let source = IntervalSet::from_iter([*source]);
+            let hit = unmapped.intersection(&source);
+
+            mapped.extend(hit.iter().map(|x| x.shift(*offset)));
+            unmapped = unmapped.difference(&source);
+        }
+
+        mapped.union(&unmapped)
+    }
+}
+
+#[test]
+fn test_interval() {
+    let interval = Interval::new(3, 8);
+
+    assert_eq!(interval.len(), 5);
+    assert!(interval.contains(3));
+    assert!(!interval.contains(8));
+    assert_eq!(
+        interval.split_at(5),
+        (Some(Interval::new(3, 5)), Some(Interval::new(5, 8)))
+    );
+    assert_eq!(interval.split_at(3), (None, Some(interval)));
+    assert_eq!(interval.split_at(10), (Some(interval), None));
+    assert_eq!(
+        interval.intersection(&Interval::new(6, 20)),
+        Some(Interval::new(6, 8))
+    );
+    assert_eq!(interval.intersection(&Interval::new(8, 20)), None);
+}
+
+#[test]
+fn test_interval_set_insert_merges() {
+    let set: IntervalSet = [
+        Interval::new(10, 12),
+        Interval::new(1, 3),
+        Interval::new(3, 5),
+        Interval::new(20, 25),
+        Interval::new(11, 21),
+    ]
+    .into_iter()
+    .collect();
+
+    assert_eq!(
+        set.iter().copied().collect::<Vec<_>>(),
+        vec![Interval::new(1, 5), Interval::new(10, 25)]
+    );
+    assert_eq!(set.len(), 19);
+    assert!(set.contains(4));
+    assert!(!set.contains(5));
+    assert_eq!(set.min(), Some(1));
+    assert_eq!(set.max(), Some(24));
+}
+
+#[test]
+fn test_interval_set_operations() {
+    let a: IntervalSet = [Interval::new(0, 10), Interval::new(20, 30)]
+        .into_iter()
+        .collect();
+    let b: IntervalSet = [Interval::new(5, 25)].into_iter().collect();
+
+    assert_eq!(
+        a.union(&b),
+        [Interval::new(0, 30)].into_iter().collect::<IntervalSet>()
+    );
+    assert_eq!(
+        a.intersection(&b),
+        [Interval::new(5, 10), Interval::new(20, 25)]
+            .into_iter()
+            .collect::<IntervalSet>()
+    );
+    assert_eq!(
+        a.difference(&b),
+        [Interval::new(0, 5), Interval::new(25, 30)]
+            .into_iter()
+            .collect::<IntervalSet>()
+    );
+
+    let (lower, upper) = a.split_at(25);
+    assert_eq!(lower.len(), 15);
+    assert_eq!(upper.len(), 5);
+}
+
+#[test]
+fn test_interval_map() {
+    // seed-to-soil map from the day 5 example
+    let mut map = IntervalMap::new();
+    map.insert(Interval::new(98, 100), 50 - 98);
+    map.insert(Interval::new(50, 98), 52 - 50);
+
+    assert_eq!(map.map(79), 81);
+    assert_eq!(map.map(14), 14);
+    assert_eq!(map.map(99), 51);
+
+    let seeds: IntervalSet = [Interval::new(79, 93), Interval::new(55, 68)]
+        .into_iter()
+        .collect();
+    let soil = map.map_set(&seeds);
+
+    assert_eq!(soil.len(), seeds.len());
+    assert_eq!(
+        soil,
+        [Interval::new(57, 70), Interval::new(81, 95)]
+            .into_iter()
+            .collect::<IntervalSet>()
+    );
+
+    let edge: IntervalSet = [Interval::new(96, 102)].into_iter().collect();
+    assert_eq!(
+        map.map_set(&edge),
+        [
+            Interval::new(50, 52),
+            Interval::new(98, 100),
+            Interval::new(100, 102)
+        ]
+        .into_iter()
+        .collect::<IntervalSet>()
+    );
+}
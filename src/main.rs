@@ -1,109 +1,211 @@
 mod grid;
+mod graph;
+mod cycle;
+mod geometry;
+mod input;
+mod intervals;
+mod math;
+mod memo;
+mod parse;
+mod report;
+mod scaffold;
+mod vector;
+mod voxel;
 mod aoc_iteratorutils;
+mod aho_corasick;
 
-mod day1;
-mod day2;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day20;
-mod day21;
+mod year2023;
 
-fn main() {
-    // day1::day1_1();
-    // day1::day1_2();
-
-    // day2::day2_1();
-    // day2::day2_2();
-
-    // day3::day3_1();
-    // day3::day3_2();
-
-    // day4::day4_1();
-    // day4::day4_2();
-
-    // day5::day5_1();
-    // day5::day5_2();
-
-    // day6::day6_1();
-    // day6::day6_2();
-
-    // day7::day7_1();
-    // day7::day7_2();
-
-    // day8::day8_1();
-    // day8::day8_2();
-
-    // day9::day9_1();
-    // day9::day9_2();
-
-    // day10::day10_1();
-    // day10::day10_2();
+/// Every year's solutions. `aoc new` adds new years and days here.
+const YEARS: &[(usize, Solutions)] = &[
+    (2023, year2023::SOLUTIONS),
+];
 
-    // day11::day11_1();
-    // day11::day11_2();
+/// Solutions of a year as `(day, part, function)`.
+type Solutions = &'static [(usize, usize, fn(&str) -> String)];
 
-    // day12::day12_1();
+/// The days whose input `aoc dot` can draw.
+const GRAPHS: &[(usize, Graphs)] = &[
+    (2023, year2023::GRAPHS),
+];
 
-    // day13::day13_1();
-    // day13::day13_2();
+/// The graph in the input of a day, as `(day, function)`.
+type Graphs = &'static [(usize, fn(&str) -> Graph)];
 
-    // day14::day14_1();
-    // day14::day14_2();
+/// How `aoc run` prints its results.
+#[derive(Clone, Copy)]
+enum Format {
+    Text,
+    /// One JSON object per line and part.
+    Json,
+}
 
-    // day15::day15_1();
-    // day15::day15_2();
+/// What comes after `--` on the command line, for solutions that take options.
+static PUZZLE_ARGS: OnceLock<Vec<String>> = OnceLock::new();
 
-    // day16::day16_1();
-    // day16::day16_2();
+/// The arguments given to the solution being run, e.g. the bag of
+/// `aoc run 2023 2 1 -- 12 red, 13 green, 14 blue`.
+pub fn puzzle_args() -> &'static [String] {
+    PUZZLE_ARGS.get().map_or(&[], Vec::as_slice)
+}
 
-    // day17::day17_1();
-    // day17::day17_2();
+fn main() {
+    let mut args = env::args().skip(1).collect_vec();
+    if let Some(separator) = args.iter().position(|arg| arg == "--") {
+        let puzzle_args = args.split_off(separator + 1);
+        args.pop();
+        PUZZLE_ARGS.set(puzzle_args).unwrap();
+    }
+    let format = take_format(&mut args);
+
+    match args.iter().map(String::as_str).collect_vec()[..] {
+        ["new", year, day] => {
+            let (year, day) = (number(year, "year"), number(day, "day"));
+
+            if let Err(error) = scaffold::new_day(year, day) {
+                eprintln!("could not create {} day {}: {}", year, day, error);
+                process::exit(1);
+            }
+        }
+        ["dot", year, day] => dot(number(year, "year"), number(day, "day")),
+        ["run", year, day] => run(number(year, "year"), number(day, "day"), None, format),
+        ["run", year, day, part] => run(
+            number(year, "year"),
+            number(day, "day"),
+            Some(number(part, "part")),
+            format,
+        ),
+        _ => {
+            eprintln!("usage: aoc run <year> <day> [part] [--format text|json] [-- <puzzle args>...]");
+            eprintln!("       aoc dot <year> <day>");
+            eprintln!("       aoc new <year> <day>");
+            process::exit(1);
+        }
+    }
+}
 
-    // day18::day18_1();
-    // day18::day18_2();
+/// Removes `--format <format>` or `--format=<format>` from the arguments.
+fn take_format(args: &mut Vec<String>) -> Format {
+    let Some(index) = args.iter().position(|arg| arg.starts_with("--format")) else {
+        return Format::Text;
+    };
+
+    let value = match args.remove(index).strip_prefix("--format=") {
+        Some(value) => value.to_string(),
+        None if index < args.len() => args.remove(index),
+        None => String::new(),
+    };
+
+    match value.as_str() {
+        "text" => Format::Text,
+        "json" => Format::Json,
+        _ => {
+            eprintln!("the format should be text or json, not {:?}", value);
+            process::exit(1);
+        }
+    }
+}
 
-    // day19::day19_1();
-    // day19::day19_2();
+fn number(arg: &str, name: &str) -> usize {
+    arg.parse().unwrap_or_else(|_| {
+        eprintln!("the {} should be a number, not {:?}", name, arg);
+        process::exit(1);
+    })
+}
 
-    // day20::day20_1();
-    // day20::day20_2();
+/// Prints the graph of a day's input as DOT, and what it looks like to stderr.
+fn dot(year: usize, day: usize) {
+    let Some(&(_, graph)) = GRAPHS
+        .iter()
+        .filter(|(y, _)| *y == year)
+        .flat_map(|(_, graphs)| graphs.iter())
+        .find(|(d, _)| *d == day)
+    else {
+        eprintln!("no graph for {} day {}", year, day);
+        process::exit(1);
+    };
+
+    let input = Inputs::from_env().get(year, day).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        process::exit(1);
+    });
+    let graph = graph(&input);
+
+    print!("{}", graph.to_dot());
+    eprint!("{}", graph.summary());
+}
 
-    day21::day21_1();
-    day21::day21_2();
+fn run(year: usize, day: usize, part: Option<usize>, format: Format) {
+    let solutions = YEARS
+        .iter()
+        .filter(|(y, _)| *y == year)
+        .flat_map(|(_, solutions)| solutions.iter())
+        .filter(|(d, p, _)| *d == day && part.is_none_or(|part| part == *p))
+        .collect_vec();
+
+    if solutions.is_empty() {
+        match part {
+            Some(part) => eprintln!("no solution for {} day {} part {}", year, day, part),
+            None => eprintln!("no solution for {} day {}", year, day),
+        }
+        process::exit(1);
+    }
+
+    let inputs = Inputs::from_env();
+    let input = inputs.get(year, day).map_err(|error| error.to_string());
+    let ledger = Ledger::load(&inputs.ledger(year));
+
+    let (input, ledger) = match (input, ledger) {
+        (Ok(input), Ok(ledger)) => (input, ledger),
+        (Err(error), _) | (_, Err(error)) => {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+    };
+    let input_hash = report::fnv1a(input.as_bytes());
+
+    for &(day, part, solution) in solutions {
+        let start = Instant::now();
+        let answer = solution(&input);
+        let elapsed = start.elapsed();
+
+        let run = Run {
+            year,
+            day,
+            part,
+            // the ledger only knows the answers to the puzzle as it was asked
+            status: if puzzle_args().is_empty() {
+                ledger.status(day, part, &answer)
+            } else {
+                Status::Unknown
+            },
+            answer,
+            elapsed,
+            input_hash,
+        };
+
+        match format {
+            Format::Text => println!("{}", run),
+            Format::Json => println!("{}", run.to_json()),
+        }
+    }
 }
 
 use std::{
-    fs::File,
-    io::{self, BufRead}, collections::HashSet,
-    hash::Hash
+    time::Instant, collections::HashSet, sync::OnceLock,
+    hash::Hash, env, process,
 };
 
-use num::{Zero, Signed};
-
-pub fn read_lines(filename: &str) -> io::Lines<io::BufReader<File>> {
-    let file = File::open(filename).unwrap();
-    io::BufReader::new(file).lines()
-}
+use graph::Graph;
+use input::Inputs;
+use report::{Ledger, Run, Status};
+use itertools::Itertools;
+use num::Signed;
 
-pub type Vec2<T> = (T, T);
+pub use vector::Vec2;
 
-pub fn manhattan_distance<T: Zero + Signed + Copy + PartialOrd + std::ops::Sub>(a: &Vec2<T>, b: &Vec2<T>) -> T {
-    (a.0 - b.0).abs() + (a.1 - b.1).abs()
+pub fn manhattan_distance<T: Signed + Copy>(a: &Vec2<T>, b: &Vec2<T>) -> T {
+    a.manhattan(b)
 }
 
 pub fn has_unique_elements<T>(iter: T) -> bool
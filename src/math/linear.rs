@@ -0,0 +1,74 @@
+use num::{BigRational, Num};
+
+/// Solves `matrix * x = rhs` by Gaussian elimination. Meant for exact number types
+/// like [`BigRational`], so nothing gets lost to rounding. Returns `None` if the
+/// system doesn't have exactly one solution.
+pub fn solve<T: Num + Clone>(mut matrix: Vec<Vec<T>>, mut rhs: Vec<T>) -> Option<Vec<T>> {
+    let n = rhs.len();
+
+    for column in 0..n {
+        let pivot = (column..n).find(|&row| !matrix[row][column].is_zero())?;
+        matrix.swap(column, pivot);
+        rhs.swap(column, pivot);
+
+        for row in 0..n {
+            if row == column || matrix[row][column].is_zero() {
+                continue;
+            }
+
+            let factor = matrix[row][column].clone() / matrix[column][column].clone();
+            let pivot_row = matrix[column].clone();
+
+            for (value, pivot) in matrix[row].iter_mut().zip(pivot_row).skip(column) {
+                *value = value.clone() - factor.clone() * pivot;
+            }
+
+            rhs[row] = rhs[row].clone() - factor * rhs[column].clone();
+        }
+    }
+
+    Some(
+        rhs.into_iter()
+            .zip(matrix)
+            .enumerate()
+            .map(|(i, (value, row))| value / row[i].clone())
+            .collect(),
+    )
+}
+
+/// Shorthand for a big rational from an integer.
+pub fn big(value: i64) -> BigRational {
+    BigRational::from_integer(value.into())
+}
+
+#[test]
+fn test_solve() {
+    // x + y + z = 6, 2y + 5z = -4, 2x + 5y - z = 27
+    let matrix = vec![
+        vec![big(1), big(1), big(1)],
+        vec![big(0), big(2), big(5)],
+        vec![big(2), big(5), big(-1)],
+    ];
+    let rhs = vec![big(6), big(-4), big(27)];
+
+    assert_eq!(solve(matrix, rhs), Some(vec![big(5), big(3), big(-2)]));
+
+    // the second row is a multiple of the first
+    let matrix = vec![vec![big(1), big(2)], vec![big(2), big(4)]];
+    assert_eq!(solve(matrix, vec![big(1), big(2)]), None);
+}
+
+#[test]
+fn test_solve_fractions() {
+    // 2x + 3y = 1, 4x - y = 2
+    let matrix = vec![vec![big(2), big(3)], vec![big(4), big(-1)]];
+    let solution = solve(matrix, vec![big(1), big(2)]).unwrap();
+
+    assert_eq!(
+        solution,
+        vec![
+            BigRational::new(7.into(), 14.into()),
+            BigRational::new(0.into(), 1.into())
+        ]
+    );
+}
@@ -0,0 +1,180 @@
+pub mod linear;
+pub mod poly;
+
+use itertools::Itertools;
+
+/// Returns `(g, x, y)` such that `a * x + b * y == g == gcd(a, b)`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a.abs(), a.signum(), 0)
+    } else {
+        let (g, x, y) = extended_gcd(b, a.rem_euclid(b));
+        (g, y, x - a.div_euclid(b) * y)
+    }
+}
+
+/// Solves the system `x ≡ residue (mod modulus)` for every `(residue, modulus)` pair.
+/// The moduli don't have to be coprime. Returns the smallest non-negative solution
+/// together with the lcm of the moduli, or `None` if the congruences contradict each
+/// other.
+pub fn crt(congruences: &[(i128, i128)]) -> Option<(i128, i128)> {
+    congruences.iter().try_fold((0, 1), |(r1, m1), &(r2, m2)| {
+        let (g, p, _) = extended_gcd(m1, m2);
+
+        if (r2 - r1) % g != 0 {
+            return None;
+        }
+
+        let lcm = m1 / g * m2;
+        let k = ((r2 - r1) / g * p).rem_euclid(m2 / g);
+
+        Some(((r1 + m1 * k).rem_euclid(lcm), lcm))
+    })
+}
+
+/// Something that walks through a state space which eventually loops: after `offset`
+/// steps it repeats every `period` steps. `hits` are the steps within the first
+/// `offset + period` steps at which the walker is at a goal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Walker {
+    pub offset: i64,
+    pub period: i64,
+    pub hits: Vec<i64>,
+}
+
+impl Walker {
+    pub fn is_hit(&self, step: i64) -> bool {
+        if step < self.offset {
+            self.hits.contains(&step)
+        } else {
+            let step = self.offset + (step - self.offset) % self.period;
+            self.hits.contains(&step)
+        }
+    }
+}
+
+/// The first step at which every walker is at a goal at the same time.
+pub fn first_common_step(walkers: &[Walker]) -> Option<i64> {
+    // hits before the loop only happen once, so just check them directly
+    let before_loop = walkers
+        .iter()
+        .flat_map(|walker| walker.hits.iter().filter(|&&hit| hit < walker.offset))
+        .filter(|&&step| walkers.iter().all(|walker| walker.is_hit(step)))
+        .copied()
+        .min();
+
+    let in_loop = walkers
+        .iter()
+        .map(|walker| {
+            walker
+                .hits
+                .iter()
+                .filter(|&&hit| hit >= walker.offset)
+                .collect_vec()
+        })
+        .multi_cartesian_product()
+        .filter_map(|hits| {
+            let congruences = hits
+                .iter()
+                .zip(walkers)
+                .map(|(&&hit, walker)| (hit as i128, walker.period as i128))
+                .collect_vec();
+
+            let (residue, modulus) = crt(&congruences)?;
+
+            // every walker has to have reached the hit at least once
+            let earliest = **hits.iter().max()? as i128;
+            let step = if residue >= earliest {
+                residue
+            } else {
+                residue + (earliest - residue + modulus - 1) / modulus * modulus
+            };
+
+            i64::try_from(step).ok()
+        })
+        .min();
+
+    before_loop.into_iter().chain(in_loop).min()
+}
+
+#[test]
+fn test_extended_gcd() {
+    assert_eq!(extended_gcd(240, 46), (2, -9, 47));
+    assert_eq!(extended_gcd(7, 0), (7, 1, 0));
+
+    let (g, x, y) = extended_gcd(-12, 18);
+    assert_eq!(g, 6);
+    assert_eq!(-12 * x + 18 * y, 6);
+}
+
+#[test]
+fn test_crt() {
+    assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+    // non-coprime moduli
+    assert_eq!(crt(&[(2, 4), (4, 6)]), Some((10, 12)));
+    assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+    assert_eq!(crt(&[]), Some((0, 1)));
+}
+
+#[test]
+fn test_first_common_step() {
+    // the lucky case: every walker hits at the end of its first loop
+    let walkers = [
+        Walker {
+            offset: 1,
+            period: 4,
+            hits: vec![4],
+        },
+        Walker {
+            offset: 1,
+            period: 6,
+            hits: vec![6],
+        },
+    ];
+    assert_eq!(first_common_step(&walkers), Some(12));
+
+    // loops with a lead-in and hits in the middle of the loop
+    let walkers = [
+        Walker {
+            offset: 2,
+            period: 5,
+            hits: vec![3],
+        },
+        Walker {
+            offset: 1,
+            period: 3,
+            hits: vec![2],
+        },
+    ];
+    assert_eq!(first_common_step(&walkers), Some(8));
+
+    // a hit before the loop starts, shared by both walkers
+    let walkers = [
+        Walker {
+            offset: 10,
+            period: 7,
+            hits: vec![1, 12],
+        },
+        Walker {
+            offset: 0,
+            period: 1,
+            hits: vec![0],
+        },
+    ];
+    assert_eq!(first_common_step(&walkers), Some(1));
+
+    // walkers that are never at a goal together
+    let walkers = [
+        Walker {
+            offset: 0,
+            period: 2,
+            hits: vec![0],
+        },
+        Walker {
+            offset: 0,
+            period: 4,
+            hits: vec![1],
+        },
+    ];
+    assert_eq!(first_common_step(&walkers), None);
+}
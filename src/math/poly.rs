@@ -0,0 +1,154 @@
+use num::{rational::Ratio, One, Zero};
+
+use crate::aoc_iteratorutils::AdventOfCodeIteratorUtils;
+
+pub type Rational = Ratio<i128>;
+
+/// The differences between neighbouring values.
+pub fn differences(values: &[i128]) -> Vec<i128> {
+    values.iter().windows_pairs().map(|(a, b)| b - a).collect()
+}
+
+/// The finite difference table of `values`: the values themselves, their differences,
+/// the differences of those and so on, until a row is all zeroes or has one value.
+pub fn difference_table(values: &[i128]) -> Vec<Vec<i128>> {
+    let mut table = vec![values.to_vec()];
+
+    while let Some(row) = table.last() {
+        if row.len() <= 1 || row.iter().all(|x| *x == 0) {
+            break;
+        }
+
+        table.push(differences(row));
+    }
+
+    table
+}
+
+/// Treats `values` as samples of a polynomial at `0, 1, 2, ...` and evaluates that
+/// polynomial at `index`, which may be negative or past the end. Uses Newton's
+/// forward difference formula, so the result is exact.
+pub fn extrapolate(values: &[i128], index: i128) -> i128 {
+    let mut binomial = 1;
+
+    difference_table(values)
+        .iter()
+        .enumerate()
+        .map(|(k, row)| {
+            if k > 0 {
+                // binomial(index, k) from binomial(index, k - 1), always divides evenly
+                binomial = binomial * (index - k as i128 + 1) / k as i128;
+            }
+
+            row[0] * binomial
+        })
+        .sum()
+}
+
+/// A polynomial with exact rational coefficients, lowest degree first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polynomial {
+    pub coefficients: Vec<Rational>,
+}
+
+impl Polynomial {
+    pub fn new(coefficients: Vec<Rational>) -> Polynomial {
+        let mut polynomial = Polynomial { coefficients };
+        polynomial.trim();
+        polynomial
+    }
+
+    fn trim(&mut self) {
+        while self.coefficients.last().is_some_and(|x| x.is_zero()) {
+            self.coefficients.pop();
+        }
+    }
+
+    /// The lowest degree polynomial passing through every `(x, y)` point, using
+    /// Lagrange interpolation. The x values must be distinct.
+    pub fn interpolate(points: &[(i128, i128)]) -> Polynomial {
+        let mut coefficients = vec![Rational::zero(); points.len()];
+
+        for (i, &(xi, yi)) in points.iter().enumerate() {
+            // build the basis polynomial which is 1 at xi and 0 at every other x
+            let mut basis = vec![Rational::one()];
+            let mut denominator = Rational::one();
+
+            for (j, &(xj, _)) in points.iter().enumerate() {
+                if i == j {
+                    continue;
+                }
+
+                // basis *= (x - xj)
+                let mut next = vec![Rational::zero(); basis.len() + 1];
+                for (k, coefficient) in basis.iter().enumerate() {
+                    next[k + 1] += coefficient;
+                    next[k] -= coefficient * xj;
+                }
+                basis = next;
+
+                denominator *= Rational::from(xi - xj);
+            }
+
+            for (k, coefficient) in basis.iter().enumerate() {
+                coefficients[k] += coefficient * yi / denominator;
+            }
+        }
+
+        Polynomial::new(coefficients)
+    }
+
+    pub fn eval(&self, x: Rational) -> Rational {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(Rational::zero(), |acc, coefficient| acc * x + coefficient)
+    }
+}
+
+#[test]
+fn test_difference_table() {
+    assert_eq!(
+        difference_table(&[1, 3, 6, 10, 15, 21]),
+        vec![
+            vec![1, 3, 6, 10, 15, 21],
+            vec![2, 3, 4, 5, 6],
+            vec![1, 1, 1, 1],
+            vec![0, 0, 0]
+        ]
+    );
+    assert_eq!(difference_table(&[7]), vec![vec![7]]);
+}
+
+#[test]
+fn test_extrapolate() {
+    // the day 9 example histories
+    assert_eq!(extrapolate(&[0, 3, 6, 9, 12, 15], 6), 18);
+    assert_eq!(extrapolate(&[1, 3, 6, 10, 15, 21], 6), 28);
+    assert_eq!(extrapolate(&[10, 13, 16, 21, 30, 45], 6), 68);
+
+    assert_eq!(extrapolate(&[0, 3, 6, 9, 12, 15], -1), -3);
+    assert_eq!(extrapolate(&[1, 3, 6, 10, 15, 21], -1), 0);
+    assert_eq!(extrapolate(&[10, 13, 16, 21, 30, 45], -1), 5);
+
+    // x^3 sampled at 0..4, evaluated far away in both directions
+    assert_eq!(extrapolate(&[0, 1, 8, 27], 1000), 1_000_000_000);
+    assert_eq!(extrapolate(&[0, 1, 8, 27], -20), -8000);
+}
+
+#[test]
+fn test_interpolate() {
+    // 1/2 x^2 - 3x + 2
+    let polynomial = Polynomial::interpolate(&[(-2, 10), (0, 2), (4, -2)]);
+
+    assert_eq!(
+        polynomial.coefficients,
+        vec![Rational::from(2), Rational::from(-3), Rational::new(1, 2)]
+    );
+    assert_eq!(polynomial.eval(Rational::from(1)), Rational::new(-1, 2));
+    assert_eq!(polynomial.eval(Rational::from(-10)), Rational::from(82));
+
+    let line = Polynomial::interpolate(&[(1, 5), (2, 5), (3, 5)]);
+    assert_eq!(line.coefficients, vec![Rational::from(5)]);
+    assert_eq!(line.eval(Rational::from(100)), Rational::from(5));
+}
@@ -0,0 +1,99 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// How often a [`Memo`] could answer from its cache.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoStats {
+    pub hits: usize,
+    pub misses: usize,
+}
+
+/// A cache for recursive solvers. The computation gets the memo back, so it can
+/// recurse through it:
+///
+/// ```ignore
+/// fn fibonacci(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+///     memo.get_or_compute(n, |memo| match n {
+///         0 | 1 => n,
+///         n => fibonacci(memo, n - 1) + fibonacci(memo, n - 2),
+///     })
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    stats: MemoStats,
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Memo {
+            cache: HashMap::new(),
+            stats: MemoStats::default(),
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Memo<K, V> {
+    pub fn new() -> Memo<K, V> {
+        Self::default()
+    }
+
+    /// The cached value for `key`, computing and storing it first if needed.
+    pub fn get_or_compute<F>(&mut self, key: K, compute: F) -> V
+    where
+        F: FnOnce(&mut Self) -> V,
+    {
+        if let Some(value) = self.cache.get(&key) {
+            self.stats.hits += 1;
+            return value.clone();
+        }
+
+        self.stats.misses += 1;
+
+        let value = compute(self);
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        self.stats
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    /// Forgets every cached value, but keeps the statistics.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+}
+
+#[test]
+fn test_memo() {
+    fn fibonacci(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+        memo.get_or_compute(n, |memo| match n {
+            0 | 1 => n,
+            n => fibonacci(memo, n - 1) + fibonacci(memo, n - 2),
+        })
+    }
+
+    let mut memo = Memo::new();
+
+    assert_eq!(fibonacci(&mut memo, 90), 2880067194370816120);
+    assert_eq!(memo.len(), 91);
+    assert_eq!(
+        memo.stats(),
+        MemoStats {
+            hits: 88,
+            misses: 91
+        }
+    );
+
+    assert_eq!(fibonacci(&mut memo, 50), 12586269025);
+    assert_eq!(memo.stats().hits, 89);
+}
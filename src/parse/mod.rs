@@ -0,0 +1,215 @@
+use std::{fmt, str::FromStr};
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{digit1, line_ending, multispace0, one_of, space0, space1},
+    combinator::{eof, map_res, opt, recognize},
+    error::{Error, ErrorKind},
+    multi::{many0, separated_list1},
+    sequence::{pair, preceded, separated_pair, terminated},
+    Finish, IResult, Offset, Parser,
+};
+
+use crate::aoc_iteratorutils::AdventOfCodeIteratorUtils;
+
+/// An unsigned integer such as `42`.
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(digit1, str::parse).parse(input)
+}
+
+/// An integer with an optional sign, such as `-42` or `+7`.
+pub fn signed<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(recognize(pair(opt(one_of("+-")), digit1)), str::parse).parse(input)
+}
+
+/// Whitespace separated numbers on a single line, like `7  15   30` or `0 -3 6`.
+/// Leading spaces are skipped.
+pub fn numbers<T: FromStr>(input: &str) -> IResult<&str, Vec<T>> {
+    preceded(space0, separated_list1(space1, signed)).parse(input)
+}
+
+/// Any number of line breaks, including none.
+pub fn newlines(input: &str) -> IResult<&str, Vec<&str>> {
+    many0(line_ending).parse(input)
+}
+
+/// Blocks of lines separated by blank lines, like the patterns in day 13, taking
+/// the rest of the input. The lines are grouped by `paragraphs()`, so repeated blank
+/// lines don't produce empty blocks. A block is the text of its lines without the
+/// final line break.
+pub fn blocks(input: &str) -> IResult<&str, Vec<&str>> {
+    let blocks = input
+        .lines()
+        .paragraphs()
+        .map(|lines| {
+            let (first, last) = (lines[0], lines[lines.len() - 1]);
+            &input[input.offset(first)..input.offset(last) + last.len()]
+        })
+        .collect::<Vec<_>>();
+
+    if blocks.is_empty() {
+        return Err(nom::Err::Error(Error::new(input, ErrorKind::SeparatedList)));
+    }
+
+    Ok((&input[input.len()..], blocks))
+}
+
+/// One `parser` match per line, like the games in day 2 or the hands in day 7.
+pub fn lines<'a, O, P>(parser: P) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    P: Parser<&'a str, O, Error<&'a str>>,
+{
+    separated_list1(line_ending, parser)
+}
+
+/// A `key: value` pair, e.g. `Time:      7  15   30`.
+pub fn key_value<'a, K, V, F, G>(
+    key: F,
+    value: G,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (K, V)>
+where
+    F: Parser<&'a str, K, Error<&'a str>>,
+    G: Parser<&'a str, V, Error<&'a str>>,
+{
+    separated_pair(key, pair(tag(":"), space0), value)
+}
+
+/// Where and why parsing a whole input failed. `line` starts at 1 and `remaining` is
+/// the rest of that line from the point where parsing stopped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub kind: ErrorKind,
+    pub line: usize,
+    pub remaining: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ErrorKind::Eof => write!(
+                f,
+                "line {}: unexpected trailing input: {:?}",
+                self.line, self.remaining
+            ),
+            kind => write!(
+                f,
+                "line {}: {} error at: {:?}",
+                self.line,
+                kind.description(),
+                self.remaining
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Runs `parser` on the whole input. Trailing whitespace is allowed, but anything
+/// else left over is an error pointing at the line where it starts.
+pub fn parse_all<'a, O, P>(parser: P, input: &'a str) -> Result<O, ParseError>
+where
+    P: Parser<&'a str, O, Error<&'a str>>,
+{
+    terminated(parser, pair(multispace0, eof))
+        .parse(input)
+        .finish()
+        .map(|(_, output)| output)
+        .map_err(|error| {
+            let offset = input.len() - error.input.len();
+
+            ParseError {
+                kind: error.code,
+                line: input[..offset].matches('\n').count() + 1,
+                remaining: error.input.lines().next().unwrap_or_default().to_string(),
+            }
+        })
+}
+
+/// [`parse_all`] for a puzzle input, which should always parse. Panics with the
+/// [`ParseError`] message otherwise, e.g. `line 2: unexpected trailing input: "x 3"`.
+pub fn parse_input<'a, O, P>(parser: P, input: &'a str) -> O
+where
+    P: Parser<&'a str, O, Error<&'a str>>,
+{
+    parse_all(parser, input).unwrap_or_else(|error| panic!("{}", error))
+}
+
+#[test]
+fn test_integers() {
+    assert_eq!(unsigned::<usize>("42 rest"), Ok((" rest", 42)));
+    assert!(unsigned::<usize>("-42").is_err());
+    assert_eq!(signed::<i64>("-42"), Ok(("", -42)));
+    assert_eq!(signed::<i64>("+7,"), Ok((",", 7)));
+}
+
+#[test]
+fn test_numbers() {
+    assert_eq!(
+        numbers::<i64>("  7  15   30\n"),
+        Ok(("\n", vec![7, 15, 30]))
+    );
+    assert_eq!(numbers::<i64>("0 -3 6"), Ok(("", vec![0, -3, 6])));
+}
+
+#[test]
+fn test_blocks() {
+    let input = "\n#.#\n.#.\n\n\n##\n##\n\n";
+
+    assert_eq!(blocks(input), Ok(("", vec!["#.#\n.#.", "##\n##"])));
+    assert_eq!(
+        blocks(input)
+            .unwrap()
+            .1
+            .into_iter()
+            .map(|block| block.lines().collect::<Vec<_>>())
+            .collect::<Vec<_>>(),
+        input.lines().paragraphs().collect::<Vec<_>>()
+    );
+    assert!(blocks("\n\n").is_err());
+}
+
+#[test]
+fn test_key_value() {
+    assert_eq!(
+        key_value(tag("Time"), numbers::<usize>)("Time:      7  15   30"),
+        Ok(("", ("Time", vec![7, 15, 30])))
+    );
+}
+
+#[test]
+fn test_parse_all() {
+    assert_eq!(parse_all(numbers::<usize>, "1 2 3\n\n"), Ok(vec![1, 2, 3]));
+
+    let error = parse_all(numbers::<usize>, "1 2 3 oops").unwrap_err();
+    assert_eq!(error.kind, ErrorKind::Eof);
+    assert_eq!(error.line, 1);
+    assert_eq!(error.remaining, "oops");
+    assert_eq!(
+        error.to_string(),
+        "line 1: unexpected trailing input: \"oops\""
+    );
+}
+
+#[test]
+fn test_parse_all_reports_line() {
+    let input = "1 2\n3 4\n5 x 6\n7 8\n";
+
+    assert_eq!(
+        parse_all(lines(numbers::<usize>), "1 2\n3 4\n"),
+        Ok(vec![vec![1, 2], vec![3, 4]])
+    );
+
+    let error = parse_all(lines(numbers::<usize>), input).unwrap_err();
+    assert_eq!(error.line, 3);
+    assert_eq!(error.remaining, "x 6");
+    assert_eq!(
+        error.to_string(),
+        "line 3: unexpected trailing input: \"x 6\""
+    );
+}
+
+#[test]
+#[should_panic(expected = "line 2: unexpected trailing input: \"x 3\"")]
+fn test_parse_input() {
+    parse_input(lines(numbers::<i64>), "0 3 6\nx 3\n");
+}
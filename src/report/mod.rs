@@ -0,0 +1,192 @@
+use std::{collections::HashMap, fmt, fs, io, path::Path, time::Duration};
+
+/// How an answer compares to the one in the ledger.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Correct,
+    Wrong,
+    /// Nothing in the ledger for this part yet.
+    Unknown,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            Status::Correct => "correct",
+            Status::Wrong => "wrong",
+            Status::Unknown => "unknown",
+        };
+        write!(f, "{}", status)
+    }
+}
+
+/// The accepted answers of a year, kept next to its inputs in `answers.txt` as one
+/// `<day> <part> <answer>` per line. Blank lines and lines starting with `#` are
+/// skipped.
+#[derive(Debug, Default)]
+pub struct Ledger {
+    answers: HashMap<(usize, usize), String>,
+}
+
+impl Ledger {
+    pub fn parse(source: &str) -> Result<Ledger, String> {
+        let mut answers = HashMap::new();
+
+        for (number, line) in source.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid = || format!("line {}: expected <day> <part> <answer>", number + 1);
+            let mut fields = line.splitn(3, ' ');
+            let day = fields.next().and_then(|day| day.parse().ok());
+            let part = fields.next().and_then(|part| part.parse().ok());
+            let answer = fields
+                .next()
+                .map(str::trim)
+                .filter(|answer| !answer.is_empty());
+
+            match (day, part, answer) {
+                (Some(day), Some(part), Some(answer)) => {
+                    answers.insert((day, part), answer.to_string());
+                }
+                _ => return Err(invalid()),
+            }
+        }
+
+        Ok(Ledger { answers })
+    }
+
+    /// A missing ledger file is an empty ledger.
+    pub fn load(path: &Path) -> Result<Ledger, String> {
+        match fs::read_to_string(path) {
+            Ok(source) => {
+                Ledger::parse(&source).map_err(|error| format!("{}: {}", path.display(), error))
+            }
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Ledger::default()),
+            Err(error) => Err(format!("{}: {}", path.display(), error)),
+        }
+    }
+
+    pub fn status(&self, day: usize, part: usize, answer: &str) -> Status {
+        match self.answers.get(&(day, part)) {
+            Some(expected) if expected == answer => Status::Correct,
+            Some(_) => Status::Wrong,
+            None => Status::Unknown,
+        }
+    }
+}
+
+/// 64 bit FNV-1a, so the hash of an input stays the same across builds.
+pub fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// The outcome of running one part of a day.
+#[derive(Debug)]
+pub struct Run {
+    pub year: usize,
+    pub day: usize,
+    pub part: usize,
+    pub answer: String,
+    pub elapsed: Duration,
+    pub input_hash: u64,
+    pub status: Status,
+}
+
+impl Run {
+    /// A single line JSON object.
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"year\":{},\"day\":{},\"part\":{},\"answer\":{},\"elapsed_ms\":{:.3},\"input_hash\":\"{:016x}\",\"status\":\"{}\"}}",
+            self.year,
+            self.day,
+            self.part,
+            json_string(&self.answer),
+            self.elapsed.as_secs_f64() * 1000.0,
+            self.input_hash,
+            self.status
+        )
+    }
+}
+
+impl fmt::Display for Run {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} day {} part {}: {} ({}, {:.3?})",
+            self.year, self.day, self.part, self.answer, self.status, self.elapsed
+        )
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut json = String::from("\"");
+
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+
+    json.push('"');
+    json
+}
+
+#[test]
+fn test_ledger() {
+    let ledger = Ledger::parse(
+        "# day part answer
+1 1 56506
+
+1 2 56017
+8 2 21083806112641
+",
+    )
+    .unwrap();
+
+    assert_eq!(ledger.status(1, 1, "56506"), Status::Correct);
+    assert_eq!(ledger.status(1, 2, "56018"), Status::Wrong);
+    assert_eq!(ledger.status(2, 1, "3035"), Status::Unknown);
+    assert_eq!(ledger.status(8, 2, "21083806112641"), Status::Correct);
+
+    assert_eq!(
+        Ledger::parse("1 1 1\n1 x 2\n").unwrap_err(),
+        "line 2: expected <day> <part> <answer>"
+    );
+    assert!(Ledger::parse("3 1\n").is_err());
+}
+
+#[test]
+fn test_fnv1a() {
+    assert_eq!(fnv1a(b""), 0xcbf29ce484222325);
+    assert_eq!(fnv1a(b"a"), 0xaf63dc4c8601ec8c);
+    assert_eq!(fnv1a(b"foobar"), 0x85944171f73967e8);
+}
+
+#[test]
+fn test_to_json() {
+    let run = Run {
+        year: 2023,
+        day: 5,
+        part: 2,
+        answer: "say \"hi\"\n".to_string(),
+        elapsed: Duration::from_micros(1500),
+        input_hash: 0xabc,
+        status: Status::Unknown,
+    };
+
+    assert_eq!(
+        run.to_json(),
+        r#"{"year":2023,"day":5,"part":2,"answer":"say \"hi\"\n","elapsed_ms":1.500,"input_hash":"0000000000000abc","status":"unknown"}"#
+    );
+}
@@ -0,0 +1,254 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, ErrorKind},
+    path::Path,
+};
+
+const TEMPLATE: &str = r#"fn part_1(input: &str) -> usize {
+    input.lines().count()
+}
+
+fn part_2(input: &str) -> usize {
+    input.lines().count()
+}
+
+#[test]
+fn test_example() {
+    // paste the example from the puzzle description here
+    let input = "";
+
+    assert_eq!(part_1(input), 0);
+    assert_eq!(part_2(input), 0);
+}
+
+pub fn dayN_1(input: &str) -> String {
+    part_1(input).to_string()
+}
+
+pub fn dayN_2(input: &str) -> String {
+    part_2(input).to_string()
+}
+"#;
+
+const YEAR_TEMPLATE: &str = "use crate::Solutions;
+
+/// Every solution of the year as `(day, part, function)`.
+pub const SOLUTIONS: Solutions = &[
+];
+";
+
+/// The source of a new day module.
+fn template(day: usize) -> String {
+    TEMPLATE.replace("dayN", &format!("day{}", day))
+}
+
+/// `source` with `mod {module};` declared after the other `mod {prefix}...` lines
+/// and `entries` appended to the `table` constant. Returns `None` if the module is
+/// already declared or there is no such table.
+fn register(
+    source: &str,
+    prefix: &str,
+    module: &str,
+    table: &str,
+    entries: &[String],
+) -> Option<String> {
+    let declaration = format!("mod {};", module);
+    if source.lines().any(|line| line == declaration) {
+        return None;
+    }
+
+    let mut lines = source.lines().map(str::to_string).collect::<Vec<_>>();
+
+    let table = lines.iter().position(|line| line.contains(table))?;
+    let table_end = table + lines[table..].iter().position(|line| line == "];")?;
+    lines.splice(table_end..table_end, entries.iter().cloned());
+
+    let last_module = lines
+        .iter()
+        .rposition(|line| line.starts_with(&format!("mod {}", prefix)));
+
+    match last_module {
+        Some(last) => lines.insert(last + 1, declaration),
+        None => {
+            lines.insert(0, String::new());
+            lines.insert(0, declaration);
+        }
+    }
+
+    Some(lines.join("\n") + "\n")
+}
+
+/// Adds a day to a year module.
+fn register_day(year_module: &str, day: usize) -> Option<String> {
+    let entries = (1..=2)
+        .map(|part| format!("    ({day}, {part}, day{day}::day{day}_{part}),"))
+        .collect::<Vec<_>>();
+
+    register(
+        year_module,
+        "day",
+        &format!("day{}", day),
+        "const SOLUTIONS",
+        &entries,
+    )
+}
+
+/// Adds a year to `main.rs`.
+fn register_year(main: &str, year: usize) -> Option<String> {
+    let entries = [format!("    ({year}, year{year}::SOLUTIONS),")];
+
+    register(
+        main,
+        "year",
+        &format!("year{}", year),
+        "const YEARS",
+        &entries,
+    )
+}
+
+/// Creates `src/yearY/dayN.rs`, an empty `inputs/Y/dayN.txt` and registers the day,
+/// setting up the year first if it's new. Paths are relative to the crate root,
+/// like the inputs. Nothing is written for a day outside `1..=25`.
+pub fn new_day(year: usize, day: usize) -> io::Result<()> {
+    if !(1..=25).contains(&day) {
+        return Err(io::Error::new(
+            ErrorKind::InvalidInput,
+            format!("there is no day {}, only days 1 to 25", day),
+        ));
+    }
+
+    let invalid = |message: String| io::Error::new(ErrorKind::InvalidData, message);
+
+    let year_dir = format!("src/year{}", year);
+    let source = format!("{}/day{}.rs", year_dir, day);
+    if Path::new(&source).exists() {
+        return Err(io::Error::new(
+            ErrorKind::AlreadyExists,
+            format!("{} already exists", source),
+        ));
+    }
+
+    let year_module = format!("{}/mod.rs", year_dir);
+    if !Path::new(&year_module).exists() {
+        let main = fs::read_to_string("src/main.rs")?;
+        let main = register_year(&main, year)
+            .ok_or_else(|| invalid(format!("could not add {} to src/main.rs", year)))?;
+
+        fs::create_dir_all(&year_dir)?;
+        fs::write(&year_module, YEAR_TEMPLATE)?;
+        fs::write("src/main.rs", main)?;
+    }
+
+    let module = fs::read_to_string(&year_module)?;
+    let module = register_day(&module, day)
+        .ok_or_else(|| invalid(format!("day {} is already in {}", day, year_module)))?;
+
+    fs::write(&source, template(day))?;
+    fs::write(&year_module, module)?;
+
+    // keep an input that's already there
+    let inputs = format!("inputs/{}", year);
+    fs::create_dir_all(&inputs)?;
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(format!("{}/day{}.txt", inputs, day))?;
+
+    println!("created {} and registered it in {}", source, year_module);
+
+    Ok(())
+}
+
+#[test]
+fn test_new_day_out_of_range() {
+    for day in [0, 26] {
+        let error = new_day(2023, day).unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+        assert!(!Path::new(&format!("src/year2023/day{}.rs", day)).exists());
+    }
+}
+
+#[test]
+fn test_template() {
+    let source = template(25);
+
+    assert!(source.contains("pub fn day25_1(input: &str) -> String {"));
+    assert!(source.contains("pub fn day25_2(input: &str) -> String {"));
+    assert!(!source.contains("dayN"));
+}
+
+#[test]
+fn test_register_day() {
+    let module = "mod day1;
+mod day2;
+
+use crate::Solutions;
+
+pub const SOLUTIONS: Solutions = &[
+    (1, 1, day1::day1_1),
+    (2, 2, day2::day2_2),
+];
+";
+
+    assert_eq!(
+        register_day(module, 3).unwrap(),
+        "mod day1;
+mod day2;
+mod day3;
+
+use crate::Solutions;
+
+pub const SOLUTIONS: Solutions = &[
+    (1, 1, day1::day1_1),
+    (2, 2, day2::day2_2),
+    (3, 1, day3::day3_1),
+    (3, 2, day3::day3_2),
+];
+"
+    );
+    assert_eq!(register_day(module, 2), None);
+    assert_eq!(register_day("fn main() {}\n", 3), None);
+
+    // the first day of a new year
+    assert_eq!(
+        register_day(YEAR_TEMPLATE, 1).unwrap(),
+        "mod day1;
+
+use crate::Solutions;
+
+/// Every solution of the year as `(day, part, function)`.
+pub const SOLUTIONS: Solutions = &[
+    (1, 1, day1::day1_1),
+    (1, 2, day1::day1_2),
+];
+"
+    );
+}
+
+#[test]
+fn test_register_year() {
+    let main = "mod grid;
+
+mod year2023;
+
+const YEARS: &[(usize, Solutions)] = &[
+    (2023, year2023::SOLUTIONS),
+];
+";
+
+    assert_eq!(
+        register_year(main, 2024).unwrap(),
+        "mod grid;
+
+mod year2023;
+mod year2024;
+
+const YEARS: &[(usize, Solutions)] = &[
+    (2023, year2023::SOLUTIONS),
+    (2024, year2024::SOLUTIONS),
+];
+"
+    );
+    assert_eq!(register_year(main, 2023), None);
+}
@@ -0,0 +1,225 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use num::Signed;
+
+/// A 2D vector, used both for positions and directions. The y axis points down, like
+/// the rows of a [`Grid`](crate::grid::Grid).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Vec2<T>(pub T, pub T);
+
+impl<T> Vec2<T> {
+    pub fn new(x: T, y: T) -> Vec2<T> {
+        Vec2(x, y)
+    }
+}
+
+impl<T: Copy + Signed> Vec2<T> {
+    pub fn manhattan(&self, other: &Vec2<T>) -> T {
+        (self.0 - other.0).abs() + (self.1 - other.1).abs()
+    }
+}
+
+impl<T: Copy + Neg<Output = T>> Vec2<T> {
+    /// Rotates 90° clockwise as seen on screen, e.g. east becomes south.
+    pub fn rotate_right(self) -> Vec2<T> {
+        Vec2(-self.1, self.0)
+    }
+
+    /// Rotates 90° counter-clockwise as seen on screen, e.g. east becomes north.
+    pub fn rotate_left(self) -> Vec2<T> {
+        Vec2(self.1, -self.0)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn add(self, rhs: Vec2<T>) -> Vec2<T> {
+        Vec2(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+
+impl<T: Copy + Add<Output = T>> AddAssign for Vec2<T> {
+    fn add_assign(&mut self, rhs: Vec2<T>) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn sub(self, rhs: Vec2<T>) -> Vec2<T> {
+        Vec2(self.0 - rhs.0, self.1 - rhs.1)
+    }
+}
+
+impl<T: Copy + Sub<Output = T>> SubAssign for Vec2<T> {
+    fn sub_assign(&mut self, rhs: Vec2<T>) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn mul(self, rhs: T) -> Vec2<T> {
+        Vec2(self.0 * rhs, self.1 * rhs)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn neg(self) -> Vec2<T> {
+        Vec2(-self.0, -self.1)
+    }
+}
+
+impl<T> From<(T, T)> for Vec2<T> {
+    fn from((x, y): (T, T)) -> Vec2<T> {
+        Vec2(x, y)
+    }
+}
+
+impl<T> From<Vec2<T>> for (T, T) {
+    fn from(Vec2(x, y): Vec2<T>) -> (T, T) {
+        (x, y)
+    }
+}
+
+/// A 3D vector. The z axis points up, as in the falling brick and hailstone puzzles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Vec3<T>(pub T, pub T, pub T);
+
+impl<T> Vec3<T> {
+    pub fn new(x: T, y: T, z: T) -> Vec3<T> {
+        Vec3(x, y, z)
+    }
+}
+
+impl<T: Copy> Vec3<T> {
+    /// Projection onto the XY plane, dropping the height.
+    pub fn xy(&self) -> Vec2<T> {
+        Vec2(self.0, self.1)
+    }
+}
+
+impl<T: Copy + Signed> Vec3<T> {
+    pub fn manhattan(&self, other: &Vec3<T>) -> T {
+        (self.0 - other.0).abs() + (self.1 - other.1).abs() + (self.2 - other.2).abs()
+    }
+}
+
+impl<T: Add<Output = T>> Add for Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn add(self, rhs: Vec3<T>) -> Vec3<T> {
+        Vec3(self.0 + rhs.0, self.1 + rhs.1, self.2 + rhs.2)
+    }
+}
+
+impl<T: Copy + Add<Output = T>> AddAssign for Vec3<T> {
+    fn add_assign(&mut self, rhs: Vec3<T>) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn sub(self, rhs: Vec3<T>) -> Vec3<T> {
+        Vec3(self.0 - rhs.0, self.1 - rhs.1, self.2 - rhs.2)
+    }
+}
+
+impl<T: Copy + Sub<Output = T>> SubAssign for Vec3<T> {
+    fn sub_assign(&mut self, rhs: Vec3<T>) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn mul(self, rhs: T) -> Vec3<T> {
+        Vec3(self.0 * rhs, self.1 * rhs, self.2 * rhs)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn neg(self) -> Vec3<T> {
+        Vec3(-self.0, -self.1, -self.2)
+    }
+}
+
+impl<T> From<(T, T, T)> for Vec3<T> {
+    fn from((x, y, z): (T, T, T)) -> Vec3<T> {
+        Vec3(x, y, z)
+    }
+}
+
+impl<T> From<Vec3<T>> for (T, T, T) {
+    fn from(Vec3(x, y, z): Vec3<T>) -> (T, T, T) {
+        (x, y, z)
+    }
+}
+
+#[test]
+fn test_vec2_arithmetic() {
+    let pos = Vec2(3_i64, -2);
+    let dir = Vec2(0, 1);
+
+    assert_eq!(pos + dir, Vec2(3, -1));
+    assert_eq!(pos - dir, Vec2(3, -3));
+    assert_eq!(pos + dir * 5, Vec2(3, 3));
+    assert_eq!(-pos, Vec2(-3, 2));
+
+    let mut moved = pos;
+    moved += dir;
+    moved -= Vec2(1, 0);
+    assert_eq!(moved, Vec2(2, -1));
+}
+
+#[test]
+fn test_vec2_manhattan() {
+    assert_eq!(Vec2(1_i64, 6).manhattan(&Vec2(5, 11)), 9);
+    assert_eq!(Vec2(-2_i32, 0).manhattan(&Vec2(2, 0)), 4);
+}
+
+#[test]
+fn test_vec2_rotate() {
+    let east = Vec2(1_i64, 0);
+
+    assert_eq!(east.rotate_right(), Vec2(0, 1));
+    assert_eq!(east.rotate_left(), Vec2(0, -1));
+    assert_eq!(east.rotate_right().rotate_right(), -east);
+    assert_eq!(east.rotate_left().rotate_right(), east);
+}
+
+#[test]
+fn test_vec2_tuple_conversion() {
+    let pos: Vec2<usize> = (4, 2).into();
+    assert_eq!(pos, Vec2(4, 2));
+
+    let (x, y) = pos.into();
+    assert_eq!((x, y), (4, 2));
+}
+
+#[test]
+fn test_vec3_arithmetic() {
+    let pos = Vec3(19_i64, 13, 30);
+    let velocity = Vec3(-2, 1, -2);
+
+    assert_eq!(pos + velocity * 5, Vec3(9, 18, 20));
+    assert_eq!(pos - velocity, Vec3(21, 12, 32));
+    assert_eq!(-velocity, Vec3(2, -1, 2));
+    assert_eq!(pos.manhattan(&Vec3(18, 19, 22)), 15);
+    assert_eq!(pos.xy(), Vec2(19, 13));
+
+    let mut moved = pos;
+    moved += velocity;
+    moved -= Vec3(0, 0, 1);
+    assert_eq!(moved, Vec3(17, 14, 27));
+}
@@ -0,0 +1,263 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::vector::{Vec2, Vec3};
+
+/// An axis-aligned box of voxels. Both corners are inclusive, matching puzzle input
+/// like `1,0,1~1,2,1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cuboid {
+    pub min: Vec3<i64>,
+    pub max: Vec3<i64>,
+}
+
+impl Cuboid {
+    /// Builds a cuboid from any two opposite corners.
+    pub fn new(a: Vec3<i64>, b: Vec3<i64>) -> Cuboid {
+        Cuboid {
+            min: Vec3(a.0.min(b.0), a.1.min(b.1), a.2.min(b.2)),
+            max: Vec3(a.0.max(b.0), a.1.max(b.1), a.2.max(b.2)),
+        }
+    }
+
+    pub fn size(&self) -> Vec3<i64> {
+        self.max - self.min + Vec3(1, 1, 1)
+    }
+
+    pub fn volume(&self) -> i64 {
+        let size = self.size();
+        size.0 * size.1 * size.2
+    }
+
+    pub fn contains(&self, pos: &Vec3<i64>) -> bool {
+        (self.min.0..=self.max.0).contains(&pos.0)
+            && (self.min.1..=self.max.1).contains(&pos.1)
+            && (self.min.2..=self.max.2).contains(&pos.2)
+    }
+
+    pub fn intersects(&self, other: &Cuboid) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    pub fn intersection(&self, other: &Cuboid) -> Option<Cuboid> {
+        let min = Vec3(
+            self.min.0.max(other.min.0),
+            self.min.1.max(other.min.1),
+            self.min.2.max(other.min.2),
+        );
+        let max = Vec3(
+            self.max.0.min(other.max.0),
+            self.max.1.min(other.max.1),
+            self.max.2.min(other.max.2),
+        );
+
+        if min.0 <= max.0 && min.1 <= max.1 && min.2 <= max.2 {
+            Some(Cuboid { min, max })
+        } else {
+            None
+        }
+    }
+
+    /// Whether the two cuboids would collide if they were moved vertically, i.e. their
+    /// shadows on the XY plane overlap.
+    pub fn overlaps_xy(&self, other: &Cuboid) -> bool {
+        self.min.0 <= other.max.0
+            && other.min.0 <= self.max.0
+            && self.min.1 <= other.max.1
+            && other.min.1 <= self.max.1
+    }
+
+    pub fn translate(&self, offset: Vec3<i64>) -> Cuboid {
+        Cuboid {
+            min: self.min + offset,
+            max: self.max + offset,
+        }
+    }
+
+    /// The columns covered by the cuboid's shadow on the XY plane.
+    pub fn footprint(&self) -> impl Iterator<Item = Vec2<i64>> {
+        let (min, max) = (self.min, self.max);
+
+        (min.0..=max.0).flat_map(move |x| (min.1..=max.1).map(move |y| Vec2(x, y)))
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = Vec3<i64>> {
+        let (min, max) = (self.min, self.max);
+
+        self.footprint()
+            .flat_map(move |xy| (min.2..=max.2).map(move |z| Vec3(xy.0, xy.1, z)))
+    }
+}
+
+/// Sparse 3D occupancy map. Voxels are stored per XY column so that column and
+/// height queries don't need to scan the whole map.
+#[derive(Debug, Clone)]
+pub struct VoxelMap<V> {
+    columns: HashMap<Vec2<i64>, BTreeMap<i64, V>>,
+}
+
+impl<V> Default for VoxelMap<V> {
+    fn default() -> Self {
+        VoxelMap {
+            columns: HashMap::new(),
+        }
+    }
+}
+
+impl<V> VoxelMap<V> {
+    pub fn new() -> VoxelMap<V> {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.columns.values().map(|column| column.len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.columns.is_empty()
+    }
+
+    pub fn insert(&mut self, pos: Vec3<i64>, value: V) -> Option<V> {
+        self.columns
+            .entry(pos.xy())
+            .or_default()
+            .insert(pos.2, value)
+    }
+
+    pub fn remove(&mut self, pos: &Vec3<i64>) -> Option<V> {
+        let column = self.columns.get_mut(&pos.xy())?;
+        let value = column.remove(&pos.2);
+
+        if column.is_empty() {
+            self.columns.remove(&pos.xy());
+        }
+
+        value
+    }
+
+    pub fn get(&self, pos: &Vec3<i64>) -> Option<&V> {
+        self.columns.get(&pos.xy())?.get(&pos.2)
+    }
+
+    pub fn contains(&self, pos: &Vec3<i64>) -> bool {
+        self.get(pos).is_some()
+    }
+
+    /// Occupied voxels in a column, ordered from the bottom up.
+    pub fn column(&self, xy: &Vec2<i64>) -> impl Iterator<Item = (i64, &V)> {
+        self.columns
+            .get(xy)
+            .into_iter()
+            .flat_map(|column| column.iter().map(|(z, value)| (*z, value)))
+    }
+
+    /// The highest occupied voxel in a column.
+    pub fn top(&self, xy: &Vec2<i64>) -> Option<(i64, &V)> {
+        self.columns
+            .get(xy)?
+            .iter()
+            .next_back()
+            .map(|(z, value)| (*z, value))
+    }
+
+    /// The highest occupied voxel in a column strictly below `z`, i.e. what something
+    /// falling down the column from `z` would land on.
+    pub fn below(&self, xy: &Vec2<i64>, z: i64) -> Option<(i64, &V)> {
+        self.columns
+            .get(xy)?
+            .range(..z)
+            .next_back()
+            .map(|(z, value)| (*z, value))
+    }
+
+    pub fn height(&self, xy: &Vec2<i64>) -> Option<i64> {
+        self.top(xy).map(|(z, _)| z)
+    }
+
+    /// Smallest cuboid containing every occupied voxel.
+    pub fn bounds(&self) -> Option<Cuboid> {
+        self.iter()
+            .map(|(pos, _)| Cuboid { min: pos, max: pos })
+            .reduce(|acc, cell| {
+                Cuboid::new(
+                    Vec3(
+                        acc.min.0.min(cell.min.0),
+                        acc.min.1.min(cell.min.1),
+                        acc.min.2.min(cell.min.2),
+                    ),
+                    Vec3(
+                        acc.max.0.max(cell.max.0),
+                        acc.max.1.max(cell.max.1),
+                        acc.max.2.max(cell.max.2),
+                    ),
+                )
+            })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Vec3<i64>, &V)> {
+        self.columns.iter().flat_map(|(xy, column)| {
+            column
+                .iter()
+                .map(move |(z, value)| (Vec3(xy.0, xy.1, *z), value))
+        })
+    }
+}
+
+impl<V: Clone> VoxelMap<V> {
+    pub fn insert_cuboid(&mut self, cuboid: &Cuboid, value: V) {
+        for cell in cuboid.cells() {
+            self.insert(cell, value.clone());
+        }
+    }
+
+    pub fn remove_cuboid(&mut self, cuboid: &Cuboid) {
+        for cell in cuboid.cells() {
+            self.remove(&cell);
+        }
+    }
+}
+
+#[test]
+fn test_cuboid() {
+    let a = Cuboid::new(Vec3(1, 2, 1), Vec3(1, 0, 1));
+    let b = Cuboid::new(Vec3(0, 0, 2), Vec3(2, 0, 2));
+
+    assert_eq!(a.min, Vec3(1, 0, 1));
+    assert_eq!(a.size(), Vec3(1, 3, 1));
+    assert_eq!(a.volume(), 3);
+    assert_eq!(a.cells().count(), 3);
+    assert!(a.contains(&Vec3(1, 1, 1)));
+    assert!(!a.contains(&Vec3(1, 1, 2)));
+
+    assert!(!a.intersects(&b));
+    assert!(a.overlaps_xy(&b));
+    assert_eq!(
+        a.intersection(&b.translate(Vec3(0, 0, -1))),
+        Some(Cuboid::new(Vec3(1, 0, 1), Vec3(1, 0, 1)))
+    );
+}
+
+#[test]
+fn test_voxel_map_columns() {
+    let mut map = VoxelMap::new();
+    map.insert_cuboid(&Cuboid::new(Vec3(0, 0, 1), Vec3(2, 0, 1)), 'A');
+    map.insert_cuboid(&Cuboid::new(Vec3(1, 0, 3), Vec3(1, 0, 5)), 'B');
+
+    assert_eq!(map.len(), 6);
+    assert_eq!(map.height(&Vec2(1, 0)), Some(5));
+    assert_eq!(map.height(&Vec2(0, 0)), Some(1));
+    assert_eq!(map.height(&Vec2(0, 1)), None);
+    assert_eq!(map.below(&Vec2(1, 0), 3), Some((1, &'A')));
+    assert_eq!(map.below(&Vec2(1, 0), 1), None);
+    assert_eq!(
+        map.column(&Vec2(1, 0)).map(|(z, _)| z).collect::<Vec<_>>(),
+        vec![1, 3, 4, 5]
+    );
+    assert_eq!(
+        map.bounds(),
+        Some(Cuboid::new(Vec3(0, 0, 1), Vec3(2, 0, 5)))
+    );
+
+    map.remove_cuboid(&Cuboid::new(Vec3(1, 0, 3), Vec3(1, 0, 5)));
+    assert_eq!(map.top(&Vec2(1, 0)), Some((1, &'A')));
+    assert!(!map.contains(&Vec3(1, 0, 4)));
+}
@@ -0,0 +1,220 @@
+use std::{
+    cmp::{max_by_key, min_by_key, Reverse},
+    env, fmt, fs, process,
+};
+
+use nom::{bytes::complete::take_till1, IResult, Parser};
+
+use crate::{
+    aho_corasick::{AhoCorasick, Match},
+    parse::{key_value, lines, parse_all, unsigned},
+    puzzle_args,
+};
+
+/// Prints the first and last token of every line to stderr when set.
+const TRACE_VAR: &str = "AOC_DAY1_TRACE";
+
+const ENGLISH: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// The tokens that stand for a digit, like `7` or `seven`.
+#[derive(Debug, Clone)]
+struct Tokens {
+    tokens: Vec<(String, u32)>,
+    matcher: AhoCorasick,
+}
+
+/// Where a token was found in a line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Token<'a> {
+    text: &'a str,
+    digit: u32,
+    position: usize,
+}
+
+impl fmt::Display for Token<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} ({}) at {}", self.text, self.digit, self.position)
+    }
+}
+
+fn token(input: &str) -> IResult<&str, (&str, u32)> {
+    key_value(
+        take_till1(|c: char| c == ':' || c.is_whitespace()),
+        unsigned,
+    )
+    .parse(input)
+}
+
+impl Tokens {
+    fn new(tokens: Vec<(String, u32)>) -> Tokens {
+        let matcher = AhoCorasick::new(tokens.iter().map(|(text, _)| text));
+
+        Tokens { tokens, matcher }
+    }
+
+    /// Just `0` to `9`.
+    fn digits() -> Tokens {
+        Tokens::new((0..10).map(|digit| (digit.to_string(), digit)).collect())
+    }
+
+    /// The digits and `one` to `nine`.
+    fn english() -> Tokens {
+        Tokens::digits().with(
+            ENGLISH
+                .iter()
+                .zip(1..)
+                .map(|(word, digit)| (word.to_string(), digit)),
+        )
+    }
+
+    fn with(mut self, tokens: impl IntoIterator<Item = (String, u32)>) -> Tokens {
+        self.tokens.extend(tokens);
+        Tokens::new(self.tokens)
+    }
+
+    /// The digits and a `word: digit` table, one token per line.
+    fn parse(source: &str) -> Result<Tokens, String> {
+        let tokens = parse_all(lines(token), source).map_err(|error| error.to_string())?;
+
+        if let Some((word, digit)) = tokens.iter().find(|(_, digit)| *digit > 9) {
+            return Err(format!(
+                "{} stands for {}, which is not a digit",
+                word, digit
+            ));
+        }
+
+        Ok(Tokens::digits().with(
+            tokens
+                .into_iter()
+                .map(|(word, digit)| (word.to_string(), digit)),
+        ))
+    }
+
+    /// [`Tokens::english`], unless a file with a table to use instead is given
+    /// after `--`, e.g. `aoc run 2023 1 2 -- german.txt`.
+    fn from_args() -> Tokens {
+        let path = match puzzle_args() {
+            [] => return Tokens::english(),
+            [path] => path,
+            args => {
+                eprintln!("expected the path of a token table, not {:?}", args);
+                process::exit(1);
+            }
+        };
+
+        let tokens = fs::read_to_string(path)
+            .map_err(|error| error.to_string())
+            .and_then(|source| Tokens::parse(&source));
+
+        tokens.unwrap_or_else(|error| {
+            eprintln!("{}: {}", path, error);
+            process::exit(1);
+        })
+    }
+
+    /// The first and the last token of a line, found in a single pass. Tokens may
+    /// overlap: the first is the one that starts first and the last the one that
+    /// ends last, so `eightwo` has `eight` and `two`. Of two tokens starting or
+    /// ending at the same place the longer one wins.
+    fn calibration<'a>(&'a self, line: &str) -> Option<(Token<'a>, Token<'a>)> {
+        let matches = self.matcher.find_iter(line);
+        let (first, last) = matches.fold(None, |found: Option<(Match, Match)>, m| {
+            let Some((first, last)) = found else {
+                return Some((m, m));
+            };
+            let first = min_by_key(first, m, |m| (m.start, Reverse(m.end)));
+            let last = max_by_key(last, m, |m| (m.end, Reverse(m.start)));
+
+            Some((first, last))
+        })?;
+
+        Some((self.token(first), self.token(last)))
+    }
+
+    fn token(&self, m: Match) -> Token<'_> {
+        let (text, digit) = &self.tokens[m.pattern];
+
+        Token {
+            text,
+            digit: *digit,
+            position: m.start,
+        }
+    }
+}
+
+fn calibration_sum<'a>(tokens: &Tokens, lines: impl Iterator<Item = &'a str>) -> u32 {
+    let trace = env::var_os(TRACE_VAR).is_some();
+
+    lines
+        .map(|line| {
+            let (first, last) = tokens
+                .calibration(line)
+                .unwrap_or_else(|| panic!("no digit in {:?}", line));
+
+            if trace {
+                eprintln!("{}: first {}, last {}", line, first, last);
+            }
+
+            first.digit * 10 + last.digit
+        })
+        .sum()
+}
+
+#[test]
+fn test_calibration() {
+    let example = "1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+    assert_eq!(calibration_sum(&Tokens::digits(), example.lines()), 142);
+
+    let example = "two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+    assert_eq!(calibration_sum(&Tokens::english(), example.lines()), 281);
+}
+
+#[test]
+fn test_overlapping_tokens() {
+    let tokens = Tokens::english();
+    let (first, last) = tokens.calibration("eightwo").unwrap();
+
+    assert_eq!((first.text, first.digit, first.position), ("eight", 8, 0));
+    assert_eq!((last.text, last.digit, last.position), ("two", 2, 4));
+    assert_eq!(tokens.calibration("oneight").unwrap().1.digit, 8);
+    assert_eq!(tokens.calibration("xyz"), None);
+
+    // `bc` ends before `abcd`, but `abcd` starts first
+    let tokens = Tokens::parse("abcd: 1\nbc: 2\n").unwrap();
+    let (first, last) = tokens.calibration("xabcdx").unwrap();
+    assert_eq!((first.text, last.text), ("abcd", "abcd"));
+}
+
+#[test]
+fn test_custom_tokens() {
+    let tokens = Tokens::parse("eins: 1\nzwei: 2\ndrei: 3\nvier: 4\nfünf: 5\n").unwrap();
+
+    let (first, last) = tokens.calibration("xzweinsfünf").unwrap();
+    assert_eq!(first.to_string(), "\"zwei\" (2) at 1");
+    assert_eq!(last.to_string(), "\"fünf\" (5) at 7");
+
+    // the English words are gone, the digits stay
+    assert_eq!(tokens.calibration("one9two").unwrap().0.digit, 9);
+
+    assert!(Tokens::parse("zwölf: 12\n").is_err());
+    assert!(Tokens::parse("eins 1\n").is_err());
+}
+
+pub fn day1_1(input: &str) -> String {
+    calibration_sum(&Tokens::digits(), input.lines()).to_string()
+}
+
+pub fn day1_2(input: &str) -> String {
+    calibration_sum(&Tokens::from_args(), input.lines()).to_string()
+}
@@ -1,7 +1,11 @@
 use itertools::Itertools;
 use num::integer::Roots;
 
-use crate::{read_lines, Vec2, grid::{Grid, GridWalk, ToGrid}};
+use crate::{
+    geometry::Polygon,
+    grid::{Grid, GridWalk},
+    Vec2,
+};
 
 #[derive(Debug, PartialEq)]
 enum Direction {
@@ -13,10 +17,10 @@ enum Direction {
 
 fn direction_to_pos(direction: &Direction, position: Vec2<usize>) -> Vec2<usize> {
     match direction {
-        Direction::North => (position.0, position.1 - 1),
-        Direction::South => (position.0, position.1 + 1),
-        Direction::East => (position.0 + 1, position.1),
-        Direction::West => (position.0 - 1, position.1),
+        Direction::North => Vec2(position.0, position.1 - 1),
+        Direction::South => Vec2(position.0, position.1 + 1),
+        Direction::East => Vec2(position.0 + 1, position.1),
+        Direction::West => Vec2(position.0 - 1, position.1),
     }
 }
 
@@ -27,7 +31,7 @@ fn is_reachable(pos: Vec2<usize>, from: Vec2<usize>, map: &Vec<char>, side: usiz
             (0, -1) => Direction::South,
             (1, 0) => Direction::West,
             (-1, 0) => Direction::East,
-            _ => panic!()
+            _ => panic!(),
         };
 
         tile_directions(*tile).contains(&direction)
@@ -55,11 +59,7 @@ fn tile_directions(tile: char) -> Vec<Direction> {
     }
 }
 
-fn step_pipe(
-    position: Vec2<usize>,
-    map: &Vec<char>,
-    side: usize,
-) -> Vec<Vec2<usize>> {
+fn step_pipe(position: Vec2<usize>, map: &Vec<char>, side: usize) -> Vec<Vec2<usize>> {
     let directions_to_consider = tile_directions(map[position.1 * side + position.0]);
 
     directions_to_consider
@@ -69,18 +69,16 @@ fn step_pipe(
         .collect_vec()
 }
 
-fn walk_pipe(start_position: Vec2<usize>,
-    map: &Vec<char>,
-    side: usize) -> Vec<(usize, usize)> {
-    let mut history = vec!(start_position);
+fn walk_pipe(start_position: Vec2<usize>, map: &Vec<char>, side: usize) -> Vec<Vec2<usize>> {
+    let mut history = vec![start_position];
 
     let mut next_positions = step_pipe(start_position, &map, side);
 
     while !next_positions.is_empty() {
-        let new_pos: (usize, usize) = next_positions.pop().unwrap();
+        let new_pos: Vec2<usize> = next_positions.pop().unwrap();
 
         if history.contains(&new_pos) {
-            continue
+            continue;
         }
 
         history.push(new_pos);
@@ -99,55 +97,36 @@ fn find_start_pos(map: &Vec<char>, side: usize) -> Vec2<usize> {
         .unwrap()
         .0;
 
-    (start_pos % side, start_pos / side)
+    Vec2(start_pos % side, start_pos / side)
 }
 
-pub fn day10_1() {
-    let mut grid = read_lines("inputs/day10.txt").to_grid();
-    
+pub fn day10_1(input: &str) -> String {
+    let mut grid = Grid::new(input);
+
     let start_pos = find_start_pos(&grid.data, grid.width);
-    let path = grid.walk(&start_pos, |grid, pos| { step_pipe(*pos, &grid.data, grid.width)});
+    let path = grid.walk(&start_pos, |grid, pos| {
+        step_pipe(*pos, &grid.data, grid.width)
+    });
     let farthest_from_starting_pos = path.len() / 2;
 
-    dbg!(farthest_from_starting_pos);
-}
-
-fn enclosed_by_even_odd_rule(pos: Vec2<usize>, path: &Vec<Vec2<usize>>) -> bool {
-    let num = path.len();
-    let mut j = num - 1;
-    let mut c = false;
-
-    let (x, y) = pos;
-
-    for i in 0..num {
-        if x == path[i].0 && y == path[i].1 {
-            // point is a corner
-            return true
-        }
-
-        if (path[i].1 > y) != (path[j].1 > y) {
-            let slope: i64 = (x as i64 - path[i].0 as i64) * (path[j].1 as i64 - path[i].1 as i64) - (path[j].0 as i64 - path[i].0 as i64) * (y as i64 - path[i].1 as i64);
-            if slope == 0 {
-                // point is on boundary
-                return true
-            }
-            if (slope < 0) != (path[j].1 < path[i].1) {
-                c = !c;
-            }
-        }
-
-        j = i;
-    }
-
-    c
+    farthest_from_starting_pos.to_string()
 }
 
-pub fn day10_2() {
-    let mut grid = read_lines("inputs/day10.txt").to_grid();
+pub fn day10_2(input: &str) -> String {
+    let mut grid = Grid::new(input);
 
     let start_pos = find_start_pos(&grid.data, grid.width);
-    let path = grid.walk(&start_pos, |grid, pos| { step_pipe(*pos, &grid.data, grid.width)});
-
-    let enclosed_tiles = grid.data.iter().enumerate().filter(|(i, _)| !path.contains(&(i % grid.width, i / grid.width)) && enclosed_by_even_odd_rule((i % grid.width, i / grid.width), &path)).count();
-    dbg!(enclosed_tiles);
+    let path = grid.walk(&start_pos, |grid, pos| {
+        step_pipe(*pos, &grid.data, grid.width)
+    });
+
+    let polygon = Polygon::new(
+        path.iter()
+            .map(|pos| Vec2(pos.0 as i64, pos.1 as i64))
+            .dedup()
+            .collect_vec(),
+    );
+
+    let enclosed_tiles = polygon.interior_points();
+    enclosed_tiles.to_string()
 }
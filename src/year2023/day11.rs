@@ -1,7 +1,7 @@
 use itertools::Itertools;
 use num::integer::Roots;
 
-use crate::{manhattan_distance, read_lines, Vec2};
+use crate::{manhattan_distance, Vec2};
 
 #[derive(Debug)]
 struct Map {
@@ -16,11 +16,14 @@ impl Map {
     }
 
     fn expand(&mut self, amount: usize) -> Vec<Vec2<i64>> {
-        let rows_to_add = (0..self.width).filter(|row| (0..self.width).all(|x| self.map[row * self.width + x] == '.')).collect_vec();
-        let cols_to_add = (0..self.width).filter(|col| (0..self.width).all(|y| self.map[y * self.width + col] == '.')).collect_vec();
+        let rows_to_add = (0..self.width)
+            .filter(|row| (0..self.width).all(|x| self.map[row * self.width + x] == '.'))
+            .collect_vec();
+        let cols_to_add = (0..self.width)
+            .filter(|col| (0..self.width).all(|y| self.map[y * self.width + col] == '.'))
+            .collect_vec();
 
-        self
-            .map
+        self.map
             .iter()
             .enumerate()
             .filter(|(_, &x)| x == '#')
@@ -33,38 +36,42 @@ impl Map {
                 let x = ((i % self.width) + (cols * amount)) % new_width;
                 let y = (i / self.width) + rows * amount;
 
-                (x as i64, y as i64)
+                Vec2(x as i64, y as i64)
             })
             .collect_vec()
     }
 }
 
-pub fn day11_1() {
-    let map = read_lines("inputs/day11.txt")
-        .map(|line| line.unwrap())
+pub fn day11_1(input: &str) -> String {
+    let map = input
+        .lines()
         .flat_map(|line| line.chars().collect_vec())
         .collect_vec();
 
     let mut map = Map::new(map);
-    let pairs: i64 = map.expand(1).iter()
+    let pairs: i64 = map
+        .expand(1)
+        .iter()
         .combinations(2)
         .map(|combinations| manhattan_distance(&combinations[0], &combinations[1]))
         .sum();
 
-    dbg!(pairs);
+    pairs.to_string()
 }
 
-pub fn day11_2() {
-    let map = read_lines("inputs/day11.txt")
-        .map(|line| line.unwrap())
+pub fn day11_2(input: &str) -> String {
+    let map = input
+        .lines()
         .flat_map(|line| line.chars().collect_vec())
         .collect_vec();
 
     let mut map = Map::new(map);
-    let pairs: i64 = map.expand(1_000_000 - 1).iter()
+    let pairs: i64 = map
+        .expand(1_000_000 - 1)
+        .iter()
         .combinations(2)
         .map(|combinations| manhattan_distance(&combinations[0], &combinations[1]))
         .sum();
 
-    dbg!(pairs);
+    pairs.to_string()
 }
@@ -0,0 +1,114 @@
+use itertools::Itertools;
+use nom::{
+    bytes::complete::{is_a, tag},
+    character::complete::space1,
+    multi::separated_list1,
+    sequence::separated_pair,
+    IResult, Parser,
+};
+
+use crate::{
+    memo::Memo,
+    parse::{lines, parse_input, unsigned},
+};
+
+fn record(input: &str) -> IResult<&str, (&str, Vec<usize>)> {
+    separated_pair(is_a("?#."), space1, separated_list1(tag(","), unsigned)).parse(input)
+}
+
+#[test]
+fn test_record() {
+    assert_eq!(
+        record("???.### 1,1,3").unwrap().1,
+        ("???.###", vec![1, 1, 3])
+    );
+}
+
+/// Ways to place `groups[group..]` on `springs[spring..]`.
+fn count(
+    memo: &mut Memo<(usize, usize), usize>,
+    springs: &[u8],
+    groups: &[usize],
+    spring: usize,
+    group: usize,
+) -> usize {
+    if spring >= springs.len() {
+        return (group == groups.len()) as usize;
+    }
+
+    memo.get_or_compute((spring, group), |memo| {
+        let mut arrangements = 0;
+
+        if matches!(springs[spring], b'.' | b'?') {
+            arrangements += count(memo, springs, groups, spring + 1, group);
+        }
+
+        if matches!(springs[spring], b'#' | b'?') && group < groups.len() {
+            let end = spring + groups[group];
+
+            // the group has to fit and can't be followed directly by another damaged spring
+            if end <= springs.len()
+                && !springs[spring..end].contains(&b'.')
+                && springs.get(end) != Some(&b'#')
+            {
+                arrangements += count(memo, springs, groups, end + 1, group + 1);
+            }
+        }
+
+        arrangements
+    })
+}
+
+fn arrangements(springs: &str, groups: &[usize]) -> usize {
+    count(&mut Memo::new(), springs.as_bytes(), groups, 0, 0)
+}
+
+fn unfold(springs: &str, groups: &[usize]) -> (String, Vec<usize>) {
+    (
+        [springs; 5].join("?"),
+        groups
+            .iter()
+            .copied()
+            .cycle()
+            .take(groups.len() * 5)
+            .collect_vec(),
+    )
+}
+
+#[test]
+fn test_arrangements() {
+    let records = [
+        ("???.###", vec![1, 1, 3], 1, 1),
+        (".??..??...?##.", vec![1, 1, 3], 4, 16384),
+        ("?#?#?#?#?#?#?#?", vec![1, 3, 1, 6], 1, 1),
+        ("????.#...#...", vec![4, 1, 1], 1, 16),
+        ("????.######..#####.", vec![1, 6, 5], 4, 2500),
+        ("?###????????", vec![3, 2, 1], 10, 506250),
+    ];
+
+    for (springs, groups, folded, unfolded) in records {
+        assert_eq!(arrangements(springs, &groups), folded);
+
+        let (springs, groups) = unfold(springs, &groups);
+        assert_eq!(arrangements(&springs, &groups), unfolded);
+    }
+}
+
+pub fn day12_1(input: &str) -> String {
+    let arrangements: usize = parse_input(lines(record), input)
+        .iter()
+        .map(|(springs, groups)| arrangements(springs, groups))
+        .sum();
+
+    arrangements.to_string()
+}
+
+pub fn day12_2(input: &str) -> String {
+    let arrangements: usize = parse_input(lines(record), input)
+        .iter()
+        .map(|(springs, groups)| unfold(springs, groups))
+        .map(|(springs, groups)| arrangements(&springs, &groups))
+        .sum();
+
+    arrangements.to_string()
+}
@@ -1,6 +1,6 @@
 use itertools::Itertools;
 
-use crate::read_lines;
+use crate::parse::{blocks, parse_input};
 
 #[derive(Debug, Clone)]
 struct Pattern {
@@ -10,7 +10,7 @@ struct Pattern {
 
 impl Pattern {
     fn new(pattern: &str) -> Pattern {
-        let width = pattern.find("\n").unwrap();
+        let width = pattern.find("\n").unwrap_or(pattern.len());
 
         Pattern {
             pattern: pattern.chars().filter(|c| *c != '\n').collect_vec(),
@@ -182,35 +182,25 @@ fn fix_smudge(pattern: Pattern) -> usize {
     panic!()
 }
 
-pub fn day13_1() {
-    let sum: usize = read_lines("inputs/day13.txt")
-        .map(|line| line.unwrap() + "\n")
-        .coalesce(|x, y| match (x.as_str(), y.as_str()) {
-            (_, "\n") => Err((x, y)),
-            ("\n", _) => Ok(y),
-            _ => Ok(x + &y[..]),
-        })
-        .map(|pattern| Pattern::new(&pattern))
+pub fn day13_1(input: &str) -> String {
+    let sum: usize = parse_input(blocks, input)
+        .into_iter()
+        .map(Pattern::new)
         .map(|pattern| reflection(pattern, None))
         .map(choose_reflection)
         // .inspect(|x| println!("{:?}", x))
         .sum();
 
-    dbg!(sum);
+    sum.to_string()
 }
 
-pub fn day13_2() {
-    let sum: usize = read_lines("inputs/day13.txt")
-        .map(|line| line.unwrap() + "\n")
-        .coalesce(|x, y| match (x.as_str(), y.as_str()) {
-            (_, "\n") => Err((x, y)),
-            ("\n", _) => Ok(y),
-            _ => Ok(x + &y[..]),
-        })
-        .map(|pattern| Pattern::new(&pattern))
+pub fn day13_2(input: &str) -> String {
+    let sum: usize = parse_input(blocks, input)
+        .into_iter()
+        .map(Pattern::new)
         .map(fix_smudge)
         // .inspect(|x| println!("{:?}", x))
         .sum();
 
-    dbg!(sum);
+    sum.to_string()
 }
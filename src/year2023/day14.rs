@@ -0,0 +1,99 @@
+use crate::{cycle::fast_forward, grid::Grid, Vec2};
+
+impl Grid {
+    fn tilt_step(&mut self, direction: Vec2<i64>) -> bool {
+        let mut changed = false;
+
+        for (i, _) in self
+            .data
+            .clone()
+            .iter()
+            .enumerate()
+            .filter(|(_, element)| **element == 'O')
+        {
+            let pos = Vec2(i as i64 % self.width as i64, i as i64 / self.width as i64);
+            let tilted_pos = pos - direction;
+
+            let width = self.width as i64;
+
+            if direction == Vec2(0, 1) && tilted_pos.1 == -1 {
+                continue;
+            } else if direction == Vec2(-1, 0) && tilted_pos.0 == width {
+                continue;
+            } else if direction == Vec2(0, -1) && tilted_pos.1 == width {
+                continue;
+            } else if direction == Vec2(1, 0) && tilted_pos.0 == -1 {
+                continue;
+            }
+
+            let grid = &mut self.data;
+            let tilted = grid
+                .get_mut(tilted_pos.1 as usize * self.width as usize + tilted_pos.0 as usize)
+                .unwrap();
+
+            if *tilted != '#' && *tilted != 'O' {
+                *tilted = 'O';
+
+                let current = grid
+                    .get_mut(pos.1 as usize * self.width as usize + pos.0 as usize)
+                    .unwrap();
+                *current = '.';
+                changed = true;
+            }
+        }
+
+        changed
+    }
+
+    fn tilt(&mut self, direction: Vec2<i64>) {
+        while self.tilt_step(direction) {}
+    }
+
+    fn load(&self) -> usize {
+        self.data
+            .clone()
+            .iter()
+            .enumerate()
+            .filter(|(_, element)| **element == 'O')
+            .map(|(i, _)| {
+                let pos = (i % self.width, i / self.width);
+
+                self.width - pos.1
+            })
+            .sum()
+    }
+}
+
+pub fn day14_1(input: &str) -> String {
+    let mut grid = Grid::new(input);
+
+    let direction = Vec2(0, 1);
+
+    grid.tilt(direction);
+    grid.load().to_string()
+}
+
+impl Grid {
+    fn cycle(&mut self) {
+        self.tilt(Vec2(0, 1));
+        self.tilt(Vec2(1, 0));
+        self.tilt(Vec2(0, -1));
+        self.tilt(Vec2(-1, 0));
+    }
+}
+
+pub fn day14_2(input: &str) -> String {
+    let grid = Grid::new(input);
+
+    let grid = fast_forward(
+        grid,
+        |grid| {
+            let mut grid = grid.clone();
+            grid.cycle();
+            grid
+        },
+        1000000000,
+    );
+
+    grid.load().to_string()
+}
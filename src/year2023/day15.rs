@@ -3,14 +3,15 @@ use std::collections::HashMap;
 use indexmap::IndexMap;
 use itertools::Itertools;
 use nom::{
-    bytes::complete::take_while1,
-    character::complete::{digit1, one_of},
-    combinator::{map_res, opt},
+    bytes::complete::{tag, take_while1},
+    character::complete::one_of,
+    combinator::opt,
+    multi::separated_list1,
     sequence::tuple,
     IResult, Parser,
 };
 
-use crate::read_lines;
+use crate::parse::{parse_input, unsigned};
 
 fn hash(input: &str) -> usize {
     input
@@ -18,34 +19,30 @@ fn hash(input: &str) -> usize {
         .fold(0, |acc, current| ((acc + current as usize) * 17) % 256)
 }
 
-pub fn day15_1() {
-    let input: usize = read_lines("inputs/day15.txt")
-        .map(|line| line.unwrap())
+pub fn day15_1(input: &str) -> String {
+    let sum: usize = input
+        .lines()
         .flat_map(|line| line.split(",").map(hash).collect_vec())
         .sum();
 
-    dbg!(input);
+    sum.to_string()
 }
 
 fn instruction(input: &str) -> IResult<&str, (&str, char, Option<usize>)> {
     tuple((
         take_while1(char::is_alphabetic),
         one_of("=-"),
-        opt(map_res(digit1, str::parse::<usize>)),
+        opt(unsigned),
     ))
     .parse(input)
 }
 
-pub fn day15_2() {
-    let input = read_lines("inputs/day15.txt")
-        .map(|line| line.unwrap())
-        .flat_map(|line| line.split(",").map(|x| x.to_string()).collect_vec())
-        .collect_vec();
+pub fn day15_2(input: &str) -> String {
+    let instructions = parse_input(separated_list1(tag(","), instruction), input);
 
     let mut hashmap: HashMap<usize, IndexMap<String, usize>> = HashMap::new();
 
-    for term in input {
-        let (label, op, focal_length) = instruction(&term).unwrap().1;
+    for (label, op, focal_length) in instructions {
         let boxhash = hash(label);
         let label = label.to_string();
 
@@ -81,5 +78,5 @@ pub fn day15_2() {
         })
         .sum();
 
-    dbg!(sum);
+    sum.to_string()
 }
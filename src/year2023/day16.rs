@@ -3,8 +3,8 @@ use std::io::{self, BufRead, Lines};
 use itertools::Itertools;
 
 use crate::{
-    grid::{Grid, GridWalkWithDirection, ToGrid},
-    read_lines, Vec2,
+    grid::{Grid, GridWalkWithDirection},
+    Vec2,
 };
 
 fn energize_step(grid: &Grid, pos: &Vec2<i64>, direction: &Vec2<i64>) -> Vec<Vec2<i64>> {
@@ -13,21 +13,21 @@ fn energize_step(grid: &Grid, pos: &Vec2<i64>, direction: &Vec2<i64>) -> Vec<Vec
     match (tile, direction) {
         ('.', _) => vec![*direction],
 
-        ('/', (1, 0)) => vec![(0, -1)],
-        ('/', (-1, 0)) => vec![(0, 1)],
-        ('/', (0, -1)) => vec![(1, 0)],
-        ('/', (0, 1)) => vec![(-1, 0)],
+        ('/', Vec2(1, 0)) => vec![Vec2(0, -1)],
+        ('/', Vec2(-1, 0)) => vec![Vec2(0, 1)],
+        ('/', Vec2(0, -1)) => vec![Vec2(1, 0)],
+        ('/', Vec2(0, 1)) => vec![Vec2(-1, 0)],
 
-        ('\\', (1, 0)) => vec![(0, 1)],
-        ('\\', (-1, 0)) => vec![(0, -1)],
-        ('\\', (0, -1)) => vec![(-1, 0)],
-        ('\\', (0, 1)) => vec![(1, 0)],
+        ('\\', Vec2(1, 0)) => vec![Vec2(0, 1)],
+        ('\\', Vec2(-1, 0)) => vec![Vec2(0, -1)],
+        ('\\', Vec2(0, -1)) => vec![Vec2(-1, 0)],
+        ('\\', Vec2(0, 1)) => vec![Vec2(1, 0)],
 
-        ('|', (0, 1 | -1)) => vec![*direction],
-        ('|', (1 | -1, 0)) => vec![(0, 1), (0, -1)],
+        ('|', Vec2(0, 1 | -1)) => vec![*direction],
+        ('|', Vec2(1 | -1, 0)) => vec![Vec2(0, 1), Vec2(0, -1)],
 
-        ('-', (0, 1 | -1)) => vec![(1, 0), (-1, 0)],
-        ('-', (1 | -1, 0)) => vec![*direction],
+        ('-', Vec2(0, 1 | -1)) => vec![Vec2(1, 0), Vec2(-1, 0)],
+        ('-', Vec2(1 | -1, 0)) => vec![*direction],
         _ => panic!(),
     }
 }
@@ -38,28 +38,28 @@ impl Grid {
     }
 }
 
-pub fn day16_1() {
-    let mut grid = read_lines("inputs/day16.txt").to_grid();
+pub fn day16_1(input: &str) -> String {
+    let mut grid = Grid::new(input);
     let mut energized = grid.clone();
-    grid.walk_with_direction(&(0, 0), &(1, 0), energize_step, |pos| {
+    grid.walk_with_direction(&Vec2(0, 0), &Vec2(1, 0), energize_step, |pos| {
         energized.data[pos.1 as usize * energized.width + pos.0 as usize] = '#'
     });
 
-    dbg!(energized.energized());
+    energized.energized().to_string()
 }
 
-pub fn day16_2() {
-    let mut grid = read_lines("inputs/day16.txt").to_grid();
+pub fn day16_2(input: &str) -> String {
+    let mut grid = Grid::new(input);
     let length = grid.data.len();
     let width = grid.width;
 
-    let max_energized = [(1_i64, 0_i64), (-1, 0), (0, 1), (0, -1)]
+    let max_energized = [Vec2(1_i64, 0_i64), Vec2(-1, 0), Vec2(0, 1), Vec2(0, -1)]
         .iter()
         .flat_map(|direction| {
             (0..length)
                 .map(|i| (i % width, i / width))
                 .filter(|pos| pos.0 == 0 || pos.0 == width - 1 || pos.1 == 0 || pos.1 == width - 1)
-                .map(|start_pos| ((start_pos.0 as i64, start_pos.1 as i64), *direction))
+                .map(|start_pos| (Vec2(start_pos.0 as i64, start_pos.1 as i64), *direction))
         })
         .map(|(pos, direction)| {
             let mut energized = grid.clone();
@@ -70,5 +70,5 @@ pub fn day16_2() {
         })
         .max();
 
-    dbg!(max_energized);
+    max_energized.unwrap().to_string()
 }
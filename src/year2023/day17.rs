@@ -1,18 +1,22 @@
-use std::{collections::{HashMap, BinaryHeap}, cmp::Reverse};
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+};
 
 use crate::{
-    grid::{GridBounds, GridGet, ToGrid, Grid}, read_lines, Vec2,
+    grid::{Grid, GridBounds, GridGet},
+    Vec2,
 };
 
 fn same_direction(pos: &Vec2<i64>, came_from: &HashMap<Vec2<i64>, Option<Vec2<i64>>>) -> bool {
     if let Some(Some(last1)) = came_from.get(pos) {
-        let dir = (pos.0 - last1.0, pos.1 - last1.1);
+        let dir = *pos - *last1;
 
         if let Some(Some(last2)) = came_from.get(last1) {
-            let dir2 = (last1.0 - last2.0, last1.1 - last2.1);
+            let dir2 = *last1 - *last2;
 
             if let Some(Some(last3)) = came_from.get(last2) {
-                let dir3 = (last2.0 - last3.0, last2.1 - last3.1);
+                let dir3 = *last2 - *last3;
 
                 if dir == dir2 && dir2 == dir3 {
                     return true;
@@ -24,11 +28,16 @@ fn same_direction(pos: &Vec2<i64>, came_from: &HashMap<Vec2<i64>, Option<Vec2<i6
     false
 }
 
-fn find_coldest_path_using_dijkstra(grid: &Grid, end_pos: Vec2<i64>, min: usize, max: usize) -> i64 {
+fn find_coldest_path_using_dijkstra(
+    grid: &Grid,
+    end_pos: Vec2<i64>,
+    min: usize,
+    max: usize,
+) -> i64 {
     let mut distances = HashMap::new();
     let mut q = BinaryHeap::new();
 
-    q.push((Reverse(0), (0, 0), (0, 0)));
+    q.push((Reverse(0), Vec2(0, 0), Vec2(0, 0)));
 
     while let Some((Reverse(cost), pos, dir)) = q.pop() {
         if pos == end_pos {
@@ -39,23 +48,23 @@ fn find_coldest_path_using_dijkstra(grid: &Grid, end_pos: Vec2<i64>, min: usize,
             continue;
         }
 
-        for new_dir in [(-1,0), (1,0), (0, -1), (0, 1)] {
-            if dir == new_dir || dir == (-new_dir.0, -new_dir.1) {
-                continue
+        for new_dir in [Vec2(-1, 0), Vec2(1, 0), Vec2(0, -1), Vec2(0, 1)] {
+            if dir == new_dir || dir == -new_dir {
+                continue;
             }
 
             let mut new_cost = cost;
 
             for distance in 1..=max {
-                let new_pos = (pos.0 + new_dir.0 * distance as i64, pos.1 + new_dir.1 * distance as i64);
+                let new_pos = pos + new_dir * distance as i64;
                 if !grid.in_bounds(&new_pos) {
-                    continue
+                    continue;
                 }
 
                 new_cost += grid.get(&new_pos) as i64 - '0' as i64;
 
                 if distance < min {
-                    continue
+                    continue;
                 }
 
                 if new_cost < *distances.get(&(new_pos, new_dir)).unwrap_or(&i64::MAX) {
@@ -69,20 +78,20 @@ fn find_coldest_path_using_dijkstra(grid: &Grid, end_pos: Vec2<i64>, min: usize,
     panic!()
 }
 
-pub fn day17_1() {
-    let grid = read_lines("inputs/day17.txt").to_grid();
+pub fn day17_1(input: &str) -> String {
+    let grid = Grid::new(input);
 
-    let end_pos = (grid.width as i64 - 1, grid.width as i64 - 1);
+    let end_pos = Vec2(grid.width as i64 - 1, grid.width as i64 - 1);
 
     let cost = find_coldest_path_using_dijkstra(&grid, end_pos, 1, 3);
-    dbg!(cost);
+    cost.to_string()
 }
 
-pub fn day17_2() {
-    let grid = read_lines("inputs/day17.txt").to_grid();
+pub fn day17_2(input: &str) -> String {
+    let grid = Grid::new(input);
 
-    let end_pos = (grid.width as i64 - 1, grid.width as i64 - 1);
+    let end_pos = Vec2(grid.width as i64 - 1, grid.width as i64 - 1);
 
     let cost = find_coldest_path_using_dijkstra(&grid, end_pos, 4, 10);
-    dbg!(cost);
+    cost.to_string()
 }
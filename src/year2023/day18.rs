@@ -0,0 +1,79 @@
+use itertools::Itertools;
+
+use nom::{
+    bytes::complete::{tag, take},
+    character::complete::{one_of, space1},
+    combinator::map_res,
+    sequence::{delimited, preceded, tuple},
+    IResult, Parser,
+};
+
+use crate::{
+    geometry::Polygon,
+    parse::{lines, parse_input, unsigned},
+    Vec2,
+};
+
+pub fn day18_1(input: &str) -> String {
+    let instructions = parse_input(lines(dig_step), input)
+        .into_iter()
+        .map(|(d, number, _color)| {
+            let dir = match d {
+                'R' => Vec2(1, 0),
+                'L' => Vec2(-1_i64, 0),
+                'U' => Vec2(0, -1_i64),
+                'D' => Vec2(0, 1),
+                _ => unreachable!(),
+            };
+
+            (dir, number)
+        })
+        .collect_vec();
+
+    let polygon = Polygon::from_instructions(Vec2(0, 0), instructions);
+
+    let enclosed = polygon.interior_points() + polygon.boundary_points();
+    enclosed.to_string()
+}
+
+fn instruction(input: &str) -> IResult<&str, (i64, i64)> {
+    delimited(
+        tag("(#"),
+        tuple((
+            map_res(take(5_usize), |x: &str| i64::from_str_radix(x, 16)),
+            map_res(take(1_usize), str::parse),
+        )),
+        tag(")"),
+    )
+    .parse(input)
+}
+
+fn dig_step(input: &str) -> IResult<&str, (char, i64, (i64, i64))> {
+    tuple((
+        one_of("RLUD"),
+        preceded(space1, unsigned),
+        preceded(space1, instruction),
+    ))
+    .parse(input)
+}
+
+pub fn day18_2(input: &str) -> String {
+    let instructions = parse_input(lines(dig_step), input)
+        .into_iter()
+        .map(|(_, _, (number, d))| {
+            let dir = match d {
+                0 => Vec2(1, 0),
+                2 => Vec2(-1_i64, 0),
+                3 => Vec2(0, -1_i64),
+                1 => Vec2(0, 1),
+                _ => unreachable!(),
+            };
+
+            (dir, number)
+        })
+        .collect_vec();
+
+    let polygon = Polygon::from_instructions(Vec2(0, 0), instructions);
+
+    (polygon.interior_points() + polygon.boundary_points()).to_string()
+}
@@ -9,7 +9,11 @@ use nom::{
     IResult, Parser,
 };
 
-use crate::read_lines;
+use crate::{
+    graph::Graph,
+    intervals::Interval,
+    parse::{parse_input, unsigned},
+};
 
 #[derive(Debug, Clone, Copy)]
 enum Rule<'a> {
@@ -25,13 +29,11 @@ fn rule(input: &str) -> IResult<&str, Rule> {
             tuple((
                 one_of::<&str, &str, Error<_>>("xmas"),
                 one_of("<>"),
-                take_while1(char::is_numeric),
+                unsigned,
                 one_of(":"),
                 take_while1(char::is_alphabetic),
             )),
-            |(var, op, num, _, dest)| {
-                Ok::<Rule<'_>, ()>(Rule::Rule(var, op, num.parse().unwrap(), dest))
-            },
+            |(var, op, num, _, dest)| Ok::<Rule<'_>, ()>(Rule::Rule(var, op, num, dest)),
         ),
         map_res(tag("A"), |_| Ok::<Rule<'_>, ()>(Rule::Accept)),
         map_res(tag("R"), |_| Ok::<Rule<'_>, ()>(Rule::Reject)),
@@ -54,25 +56,13 @@ fn part_ratings(input: &str) -> IResult<&str, (usize, usize, usize, usize)> {
     let (input, (x, _, m, _, a, _, s)) = delimited(
         tag("{"),
         tuple((
-            preceded(
-                take(2_usize),
-                map_res(take_while1(char::is_numeric), str::parse),
-            ),
+            preceded(take(2_usize), unsigned),
             tag(","),
-            preceded(
-                take(2_usize),
-                map_res(take_while1(char::is_numeric), str::parse),
-            ),
+            preceded(take(2_usize), unsigned),
             tag(","),
-            preceded(
-                take(2_usize),
-                map_res(take_while1(char::is_numeric), str::parse),
-            ),
+            preceded(take(2_usize), unsigned),
             tag(","),
-            preceded(
-                take(2_usize),
-                map_res(take_while1(char::is_numeric), str::parse),
-            ),
+            preceded(take(2_usize), unsigned),
         )),
         tag("}"),
     )
@@ -97,12 +87,33 @@ fn system(
     Ok((input, (workflows, part_ratings)))
 }
 
-pub fn day19_1() {
-    let input: String = read_lines("inputs/day19.txt")
-        .map(|line| line.unwrap() + "\n")
-        .collect();
+/// Which workflow sends parts to which, with `A` and `R` as the final destinations.
+fn workflow_graph(workflows: &[(&str, Vec<Rule>)]) -> Graph {
+    let mut graph = Graph::directed();
+
+    for (name, rules) in workflows {
+        for rule in rules {
+            let destination = match rule {
+                Rule::Rule(_, _, _, destination) | Rule::Destination(destination) => destination,
+                Rule::Accept => "A",
+                Rule::Reject => "R",
+            };
+
+            graph.add_edge(name, destination);
+        }
+    }
+
+    graph
+}
+
+pub fn graph(input: &str) -> Graph {
+    let (workflows, _) = parse_input(system, input);
+
+    workflow_graph(&workflows)
+}
 
-    let (workflows, part_ratings) = system(&input).unwrap().1;
+pub fn day19_1(input: &str) -> String {
+    let (workflows, part_ratings) = parse_input(system, input);
 
     let part_sum = part_ratings
         .iter()
@@ -157,22 +168,21 @@ pub fn day19_1() {
         .map(|(x, m, a, s)| x + m + a + s)
         .sum::<usize>();
 
-    dbg!(part_sum);
+    part_sum.to_string()
 }
 
-pub fn day19_2() {
-    let input: String = read_lines("inputs/day19.txt")
-        .map(|line| line.unwrap() + "\n")
-        .collect();
+pub fn day19_2(input: &str) -> String {
+    let (workflows, _) = parse_input(system, input);
 
-    let (workflows, _) = system(&input).unwrap().1;
+    // splitting the ratings only terminates if no workflow can send a part back
+    assert!(workflow_graph(&workflows).topological_sort().is_some());
 
     let mut distinct_part_numbers = vec![];
-    let mut stack = vec![((1, 4000), (1, 4000), (1, 4000), (1, 4000), "in", 0)];
+    let mut stack = vec![([Interval::new(1, 4001); 4], "in", 0)];
 
-    while let Some((x, m, a, s, part_name, rule_index)) = stack.pop() {
+    while let Some((ratings, part_name, rule_index)) = stack.pop() {
         if part_name == "A" {
-            distinct_part_numbers.push((x, m, a, s));
+            distinct_part_numbers.push(ratings);
             continue;
         }
 
@@ -188,51 +198,41 @@ pub fn day19_2() {
         let rule = &rules[rule_index];
 
         match rule {
-            Rule::Rule(var, op, rhs, dest) => match (var, op) {
-                ('x', '>') => {
-                    stack.push(((rhs + 1, x.1), m, a, s, *dest, 0));
-                    stack.push(((x.0, *rhs), m, a, s, part_name, rule_index + 1));
-                }
-                ('x', '<') => {
-                    stack.push(((x.0, *rhs - 1), m, a, s, *dest, 0));
-                    stack.push(((*rhs, x.1), m, a, s, part_name, rule_index + 1));
-                }
-                ('m', '>') => {
-                    stack.push((x, (rhs + 1, m.1), a, s, *dest, 0));
-                    stack.push((x, (m.0, *rhs), a, s, part_name, rule_index + 1));
-                }
-                ('m', '<') => {
-                    stack.push((x, (m.0, *rhs - 1), a, s, *dest, 0));
-                    stack.push((x, (*rhs, m.1), a, s, part_name, rule_index + 1));
-                }
-                ('a', '>') => {
-                    stack.push((x, m, (rhs + 1, a.1), s, *dest, 0));
-                    stack.push((x, m, (a.0, *rhs), s, part_name, rule_index + 1));
-                }
-                ('a', '<') => {
-                    stack.push((x, m, (a.0, *rhs - 1), s, *dest, 0));
-                    stack.push((x, m, (*rhs, a.1), s, part_name, rule_index + 1));
-                }
-                ('s', '>') => {
-                    stack.push((x, m, a, (rhs + 1, s.1), *dest, 0));
-                    stack.push((x, m, a, (s.0, *rhs), part_name, rule_index + 1));
+            Rule::Rule(var, op, rhs, dest) => {
+                let category = "xmas".find(*var).unwrap();
+                let rhs = *rhs as i64;
+
+                let (matching, rest) = match op {
+                    '>' => {
+                        let (lower, upper) = ratings[category].split_at(rhs + 1);
+                        (upper, lower)
+                    }
+                    '<' => ratings[category].split_at(rhs),
+                    _ => unreachable!(),
+                };
+
+                if let Some(matching) = matching {
+                    let mut ratings = ratings;
+                    ratings[category] = matching;
+                    stack.push((ratings, *dest, 0));
                 }
-                ('s', '<') => {
-                    stack.push((x, m, a, (s.0, *rhs - 1), *dest, 0));
-                    stack.push((x, m, a, (*rhs, s.1), part_name, rule_index + 1));
+
+                if let Some(rest) = rest {
+                    let mut ratings = ratings;
+                    ratings[category] = rest;
+                    stack.push((ratings, part_name, rule_index + 1));
                 }
-                _ => unreachable!(),
-            },
-            Rule::Destination(name) => stack.push((x, m, a, s, *name, 0)),
-            Rule::Accept => stack.push((x, m, a, s, "A", 0)),
-            Rule::Reject => stack.push((x, m, a, s, "R", 0)),
+            }
+            Rule::Destination(name) => stack.push((ratings, *name, 0)),
+            Rule::Accept => stack.push((ratings, "A", 0)),
+            Rule::Reject => stack.push((ratings, "R", 0)),
         }
     }
 
-    let sum: usize = distinct_part_numbers
+    let sum: i64 = distinct_part_numbers
         .iter()
-        .map(|(x, m, a, s)| (x.1 - x.0 + 1) * (m.1 - m.0 + 1) * (a.1 - a.0 + 1) * (s.1 - s.0 + 1))
+        .map(|ratings| ratings.iter().map(Interval::len).product::<i64>())
         .sum();
 
-    dbg!(sum);
+    sum.to_string()
 }
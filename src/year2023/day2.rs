@@ -0,0 +1,396 @@
+use std::{env, fmt, str::FromStr};
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::alpha1,
+    combinator::{cut, map_res},
+    error::ErrorKind,
+    multi::separated_list0,
+    sequence::preceded,
+    IResult,
+};
+
+use crate::{
+    parse::{key_value, lines, parse_all, unsigned, ParseError},
+    puzzle_args,
+};
+
+/// The bag part 1 checks the games against, unless another one is given after `--`.
+const PUZZLE_BAG: &str = "12 red, 13 green, 14 blue";
+/// Prints statistics per colour over all games to stderr when set.
+const STATS_VAR: &str = "AOC_DAY2_STATS";
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+impl Color {
+    const ALL: [Color; 3] = [Color::Red, Color::Green, Color::Blue];
+}
+
+impl FromStr for Color {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "red" => Ok(Color::Red),
+            "green" => Ok(Color::Green),
+            "blue" => Ok(Color::Blue),
+            _ => Err(format!("unknown colour {:?}", s)),
+        }
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let color = match self {
+            Color::Red => "red",
+            Color::Green => "green",
+            Color::Blue => "blue",
+        };
+        write!(f, "{}", color)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Cube {
+    color: Color,
+    amount: usize,
+}
+
+fn cube(input: &str) -> IResult<&str, Cube> {
+    let (input, amount) = unsigned(input)?;
+    let (input, _) = tag(" ")(input)?;
+    // a word that isn't a colour is an error, not the end of the bunch
+    let (input, color) = cut(map_res(alpha1, str::parse))(input)?;
+
+    Ok((input, Cube { color, amount }))
+}
+
+#[test]
+fn test_cube() {
+    assert_eq!(
+        cube("2 red").unwrap().1,
+        Cube {
+            color: Color::Red,
+            amount: 2
+        }
+    );
+    assert!(cube("2 purple").is_err());
+}
+
+fn bunch(input: &str) -> IResult<&str, Vec<Cube>> {
+    let (input, bunch) = separated_list0(tag(", "), cube)(input)?;
+
+    Ok((input, bunch))
+}
+
+#[test]
+fn test_bunch() {
+    assert_eq!(
+        bunch("2 red, 4 blue").unwrap().1,
+        vec!(
+            Cube {
+                color: Color::Red,
+                amount: 2
+            },
+            Cube {
+                color: Color::Blue,
+                amount: 4
+            }
+        )
+    );
+}
+
+fn bunches(input: &str) -> IResult<&str, Vec<Vec<Cube>>> {
+    let (input, bunches) = separated_list0(tag("; "), bunch)(input)?;
+
+    Ok((input, bunches))
+}
+
+#[test]
+fn test_bunches() {
+    assert_eq!(
+        bunches("2 red, 4 blue; 4 red, 2 blue").unwrap().1,
+        vec!(
+            vec!(
+                Cube {
+                    color: Color::Red,
+                    amount: 2
+                },
+                Cube {
+                    color: Color::Blue,
+                    amount: 4
+                }
+            ),
+            vec!(
+                Cube {
+                    color: Color::Red,
+                    amount: 4
+                },
+                Cube {
+                    color: Color::Blue,
+                    amount: 2
+                }
+            )
+        )
+    );
+}
+
+/// How many cubes of each colour there are.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+struct Bag {
+    red: usize,
+    green: usize,
+    blue: usize,
+}
+
+impl Bag {
+    fn amount(&self, color: Color) -> usize {
+        match color {
+            Color::Red => self.red,
+            Color::Green => self.green,
+            Color::Blue => self.blue,
+        }
+    }
+
+    fn amount_mut(&mut self, color: Color) -> &mut usize {
+        match color {
+            Color::Red => &mut self.red,
+            Color::Green => &mut self.green,
+            Color::Blue => &mut self.blue,
+        }
+    }
+
+    /// A colour mentioned twice counts twice.
+    fn of(cubes: &[Cube]) -> Bag {
+        let mut bag = Bag::default();
+        for cube in cubes {
+            *bag.amount_mut(cube.color) += cube.amount;
+        }
+        bag
+    }
+
+    /// The smallest bag holding both.
+    fn union(&self, other: &Bag) -> Bag {
+        Bag {
+            red: self.red.max(other.red),
+            green: self.green.max(other.green),
+            blue: self.blue.max(other.blue),
+        }
+    }
+
+    fn holds(&self, other: &Bag) -> bool {
+        Color::ALL
+            .iter()
+            .all(|&color| self.amount(color) >= other.amount(color))
+    }
+
+    fn power(&self) -> usize {
+        self.red * self.green * self.blue
+    }
+}
+
+/// nom only keeps that `str::parse` failed, so an unknown colour is parsed again
+/// for the reason.
+fn explain(error: ParseError) -> String {
+    let word = error
+        .remaining
+        .split(|c: char| !c.is_alphabetic())
+        .next()
+        .unwrap_or_default();
+
+    match word.parse::<Color>() {
+        Err(reason) if error.kind == ErrorKind::MapRes && !word.is_empty() => {
+            format!("line {}: {}", error.line, reason)
+        }
+        _ => error.to_string(),
+    }
+}
+
+impl FromStr for Bag {
+    type Err = String;
+
+    /// E.g. `12 red, 13 green, 14 blue`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_all(bunch, s)
+            .map(|cubes| Bag::of(&cubes))
+            .map_err(explain)
+    }
+}
+
+#[derive(Debug)]
+struct Game {
+    id: usize,
+    bunches: Vec<Vec<Cube>>,
+}
+
+fn game(input: &str) -> IResult<&str, Game> {
+    let (input, (id, bunches)) = key_value(preceded(tag("Game "), unsigned), bunches)(input)?;
+
+    Ok((input, Game { id, bunches }))
+}
+
+fn games(input: &str) -> Result<Vec<Game>, String> {
+    parse_all(lines(game), input).map_err(explain)
+}
+
+impl Game {
+    /// The fewest cubes of each colour the game could have been played with.
+    fn minimum_bag(&self) -> Bag {
+        self.bunches
+            .iter()
+            .map(|bunch| Bag::of(bunch))
+            .fold(Bag::default(), |bag, bunch| bag.union(&bunch))
+    }
+
+    fn is_possible_with(&self, bag: &Bag) -> bool {
+        bag.holds(&self.minimum_bag())
+    }
+}
+
+/// How a colour was drawn over a set of games.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+struct ColorStats {
+    color: Color,
+    /// Games that drew the colour at all.
+    games: usize,
+    /// Cubes drawn over all games.
+    drawn: usize,
+    /// The most cubes drawn at once.
+    most: usize,
+}
+
+impl fmt::Display for ColorStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: drawn in {} games, {} cubes in total, at most {} at once",
+            self.color, self.games, self.drawn, self.most
+        )
+    }
+}
+
+fn color_stats(games: &[Game]) -> Vec<ColorStats> {
+    Color::ALL
+        .iter()
+        .map(|&color| {
+            let mut stats = ColorStats {
+                color,
+                games: 0,
+                drawn: 0,
+                most: 0,
+            };
+
+            for game in games {
+                let most = game.minimum_bag().amount(color);
+                if most > 0 {
+                    stats.games += 1;
+                }
+                stats.most = stats.most.max(most);
+                stats.drawn += game
+                    .bunches
+                    .iter()
+                    .map(|bunch| Bag::of(bunch).amount(color))
+                    .sum::<usize>();
+            }
+
+            stats
+        })
+        .collect()
+}
+
+#[test]
+fn test_games() {
+    let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
+    let games = games(input).unwrap();
+
+    assert_eq!(
+        games[0].minimum_bag(),
+        Bag {
+            red: 4,
+            green: 2,
+            blue: 6
+        }
+    );
+
+    let bag = "12 red, 13 green, 14 blue".parse::<Bag>().unwrap();
+    let possible = games
+        .iter()
+        .filter(|game| game.is_possible_with(&bag))
+        .map(|game| game.id)
+        .collect::<Vec<_>>();
+    assert_eq!(possible, vec![1, 2, 5]);
+
+    let powers = games.iter().map(|game| game.minimum_bag().power());
+    assert_eq!(powers.sum::<usize>(), 2286);
+
+    assert_eq!(
+        color_stats(&games)[0],
+        ColorStats {
+            color: Color::Red,
+            games: 5,
+            drawn: 4 + 1 + 1 + 20 + 4 + 1 + 3 + 6 + 14 + 6 + 1,
+            most: 20
+        }
+    );
+}
+
+#[test]
+fn test_unknown_color() {
+    assert_eq!(
+        games("Game 1: 3 blue\nGame 2: 3 blue, 4 purple; 1 red\n").unwrap_err(),
+        "line 2: unknown colour \"purple\""
+    );
+    assert_eq!(
+        "3 blue, 4 purple".parse::<Bag>().unwrap_err(),
+        "line 1: unknown colour \"purple\""
+    );
+    // anything else keeps nom's description
+    assert_eq!(
+        "3 blue; 4 red".parse::<Bag>().unwrap_err(),
+        "line 1: unexpected trailing input: \"; 4 red\""
+    );
+    assert_eq!("4 blue".parse::<Bag>().unwrap().blue, 4);
+}
+
+pub fn day2_1(input: &str) -> String {
+    let bag = match puzzle_args() {
+        [] => PUZZLE_BAG.to_string(),
+        args => args.join(" "),
+    };
+    let bag = bag
+        .parse::<Bag>()
+        .unwrap_or_else(|error| panic!("bag {:?}: {}", bag, error));
+
+    let code: usize = games(input)
+        .unwrap_or_else(|error| panic!("{}", error))
+        .into_iter()
+        .filter(|game| game.is_possible_with(&bag))
+        .map(|game| game.id)
+        .sum();
+
+    code.to_string()
+}
+
+pub fn day2_2(input: &str) -> String {
+    let games = games(input).unwrap_or_else(|error| panic!("{}", error));
+
+    if env::var_os(STATS_VAR).is_some() {
+        for stats in color_stats(&games) {
+            eprintln!("{}", stats);
+        }
+    }
+
+    let code: usize = games.iter().map(|game| game.minimum_bag().power()).sum();
+
+    code.to_string()
+}
@@ -0,0 +1,353 @@
+use std::collections::{HashMap, VecDeque};
+
+use itertools::Itertools;
+use nom::{
+    bytes::complete::{tag, take_while1},
+    character::complete::one_of,
+    combinator::{map, opt},
+    multi::separated_list1,
+    sequence::tuple,
+    IResult, Parser,
+};
+
+use crate::{
+    cycle::detect_cycle,
+    graph::Graph,
+    math::{first_common_step, Walker},
+    parse::{lines, parse_input},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PulseType {
+    High,
+    Low,
+}
+
+#[derive(Debug, Clone)]
+enum ModuleType<'a> {
+    Broadcaster,
+    Button,
+    FlipFlop(bool),
+    Conjunction(HashMap<&'a str, PulseType>),
+}
+
+#[derive(Debug, Clone)]
+struct Module<'a> {
+    name: &'a str,
+    module_type: ModuleType<'a>,
+}
+
+/// Every module by name, with the modules it sends pulses to.
+type Modules<'a> = HashMap<&'a str, (Module<'a>, Vec<&'a str>)>;
+
+fn module_name(input: &str) -> IResult<&str, Module<'_>> {
+    let (input, (module_type, name)) =
+        tuple((opt(one_of("%&")), take_while1(char::is_alphabetic))).parse(input)?;
+
+    Ok((
+        input,
+        Module {
+            name,
+            module_type: match module_type {
+                Some(module_type) => {
+                    if module_type == '%' {
+                        ModuleType::FlipFlop(false)
+                    } else {
+                        ModuleType::Conjunction(HashMap::new())
+                    }
+                }
+                None => ModuleType::Broadcaster,
+            },
+        },
+    ))
+}
+
+fn module(input: &str) -> IResult<&str, (Module<'_>, Vec<&str>)> {
+    let (input, module) = module_name(input)?;
+    let (input, _) = tag(" -> ").parse(input)?;
+    let (input, destinations) =
+        separated_list1(tag(", "), take_while1(char::is_alphanumeric)).parse(input)?;
+
+    Ok((input, (module, destinations)))
+}
+
+fn modules(input: &str) -> IResult<&str, Modules<'_>> {
+    map(lines(module), |modules| {
+        modules
+            .into_iter()
+            .map(|(module, destinations)| (module.name, (module, destinations)))
+            .collect()
+    })
+    .parse(input)
+}
+
+fn setup_modules(input: &str) -> Modules<'_> {
+    let mut modules = parse_input(modules, input);
+    modules.insert(
+        "button",
+        (
+            Module {
+                name: "button",
+                module_type: ModuleType::Button,
+            },
+            vec!["broadcaster"],
+        ),
+    );
+
+    let lookup = modules.clone();
+
+    for (name, (module, _)) in modules.iter_mut() {
+        if let ModuleType::Conjunction(memory) = &mut module.module_type {
+            lookup
+                .iter()
+                .filter(|(_, (_, destinations))| destinations.contains(name))
+                .for_each(|(name, _)| {
+                    memory.insert(name, PulseType::Low);
+                });
+        }
+    }
+
+    modules
+}
+
+/// Presses the button once. `on_pulse` gets every pulse as its receiver, type,
+/// sender and depth, the number of modules it passed through since the button.
+/// Pulses are handled first in, first out, so in order of depth.
+fn press_button<'a>(
+    modules: &mut Modules<'a>,
+    mut on_pulse: impl FnMut(&'a str, PulseType, &'a str, usize),
+) {
+    let mut pulses = VecDeque::from([("button", PulseType::Low, "", 0)]);
+
+    while let Some((module_name, pulse, sender, depth)) = pulses.pop_front() {
+        on_pulse(module_name, pulse, sender, depth);
+
+        if let Some((ref mut module, destinations)) = modules.get_mut(module_name) {
+            if let Some(new_pulse) = match module.module_type {
+                ModuleType::Broadcaster => Some(pulse),
+                ModuleType::Button => Some(PulseType::Low),
+                ModuleType::FlipFlop(ref mut on) => match pulse {
+                    PulseType::High => None,
+                    PulseType::Low => match on {
+                        false => {
+                            *on = true;
+                            Some(PulseType::High)
+                        }
+                        true => {
+                            *on = false;
+                            Some(PulseType::Low)
+                        }
+                    },
+                },
+                ModuleType::Conjunction(ref mut memory) => {
+                    memory.insert(sender, pulse);
+
+                    let all_high = memory.iter().all(|x| *x.1 == PulseType::High);
+
+                    if all_high {
+                        Some(PulseType::Low)
+                    } else {
+                        Some(PulseType::High)
+                    }
+                }
+            } {
+                destinations
+                    .iter()
+                    .for_each(|name| pulses.push_back((*name, new_pulse, module_name, depth + 1)));
+            }
+        }
+    }
+}
+
+pub fn day20_1(input: &str) -> String {
+    let mut modules = setup_modules(input);
+
+    let mut history = vec![];
+
+    for _ in 0..1000 {
+        press_button(&mut modules, |module_name, pulse, _, _| {
+            if module_name != "button" {
+                history.push((module_name, pulse));
+            }
+        });
+    }
+
+    let (high, low): (Vec<PulseType>, Vec<PulseType>) = history
+        .into_iter()
+        .map(|(_, pulse)| pulse)
+        .partition(|pulse| *pulse == PulseType::High);
+
+    let product = high.len() * low.len();
+    product.to_string()
+}
+
+/// Which module sends pulses to which.
+fn wiring(modules: &Modules<'_>) -> Graph {
+    let mut graph = Graph::directed();
+
+    // sorted, so node ids and the DOT output are the same on every run
+    for (name, (_, destinations)) in modules.iter().sorted_by_key(|(name, _)| **name) {
+        for destination in destinations {
+            graph.add_edge(name, destination);
+        }
+    }
+
+    graph
+}
+
+pub fn graph(input: &str) -> Graph {
+    wiring(&setup_modules(input))
+}
+
+/// Every module whose pulses can eventually reach `name`, including `name` itself.
+fn ancestors<'a>(modules: &Modules<'a>, name: &str) -> Vec<&'a str> {
+    let graph = wiring(modules).reversed();
+    let reachable = graph.reachable(graph.id(name).unwrap());
+
+    modules
+        .keys()
+        .filter(|module| reachable[graph.id(module).unwrap()])
+        .copied()
+        .sorted()
+        .collect()
+}
+
+/// The memory of the given modules, in a hashable form.
+fn memory(modules: &Modules<'_>, names: &[&str]) -> Vec<bool> {
+    names
+        .iter()
+        .flat_map(|name| match &modules[name].0.module_type {
+            ModuleType::FlipFlop(on) => vec![*on],
+            ModuleType::Conjunction(memory) => memory
+                .iter()
+                .sorted_by_key(|(sender, _)| **sender)
+                .map(|(_, pulse)| *pulse == PulseType::High)
+                .collect(),
+            _ => vec![],
+        })
+        .collect()
+}
+
+pub fn day20_2(input: &str) -> String {
+    let modules = setup_modules(input);
+
+    let collectors = modules
+        .iter()
+        .filter(|(_, (_, destinations))| destinations.contains(&"rx"))
+        .collect_vec();
+    let [(collector, (module, _))] = collectors[..] else {
+        panic!(
+            "rx should have one module sending to it, not {}",
+            collectors.len()
+        );
+    };
+    if !matches!(module.module_type, ModuleType::Conjunction(_)) {
+        panic!("{} sends to rx, but isn't a conjunction", collector);
+    }
+
+    // rx gets a low pulse once the collector remembers a high pulse from every
+    // feeder at the same moment. Each feeder is followed on its own, which only
+    // works if a high pulse from it is always undone by a low one in the same press:
+    // then every press either has a window from one to the other or doesn't.
+    let mut windows = vec![];
+
+    let walkers = modules
+        .iter()
+        .filter(|(_, (_, destinations))| destinations.contains(collector))
+        .map(|(feeder, _)| {
+            let ancestors = ancestors(&modules, feeder);
+            let mut modules = modules.clone();
+
+            let mut presses = 0;
+            let mut hits = vec![];
+
+            let cycle = detect_cycle(memory(&modules, &ancestors), |_| {
+                presses += 1;
+
+                // the depths of the high pulse and of the low one after it
+                let mut window: Option<(usize, Option<usize>)> = None;
+                press_button(&mut modules, |receiver, pulse, sender, depth| {
+                    if receiver != *collector || sender != *feeder {
+                        return;
+                    }
+
+                    window = match (window, pulse) {
+                        (None, PulseType::High) => Some((depth, None)),
+                        (Some((high, None)), PulseType::Low) => Some((high, Some(depth))),
+                        (Some(_), PulseType::High) => {
+                            panic!(
+                                "{} sends more than one high pulse in press {}",
+                                feeder, presses
+                            )
+                        }
+                        (window, PulseType::Low) => window,
+                    };
+                });
+
+                match window {
+                    Some((high, Some(low))) => {
+                        hits.push(presses);
+                        windows.push((high, low));
+                    }
+                    Some((_, None)) => {
+                        panic!("the high pulse of {} outlasts press {}", feeder, presses)
+                    }
+                    None => {}
+                }
+
+                memory(&modules, &ancestors)
+            });
+
+            // step n of the cycle is the state after n presses, and press n + 1
+            // starts from it
+            let start = cycle.start + 1;
+
+            Walker {
+                offset: start as i64,
+                period: cycle.period as i64,
+                hits: hits
+                    .into_iter()
+                    .filter(|&hit| hit < start + cycle.period)
+                    .map(|hit| hit as i64)
+                    .collect(),
+            }
+        })
+        .collect_vec();
+
+    let presses = first_common_step(&walkers).expect("rx never gets a low pulse");
+
+    // the windows overlap if the last high pulse arrives before the first low one,
+    // whatever the presses in which they meet
+    let last_high = windows.iter().map(|&(high, _)| high).max();
+    let first_low = windows.iter().map(|&(_, low)| low).min();
+    assert!(
+        last_high < first_low,
+        "the feeders of {} aren't all high at the same moment",
+        collector
+    );
+
+    presses.to_string()
+}
+
+#[test]
+fn test_feeders() {
+    // f is high for a moment in every press and g in every other one
+    let input = "broadcaster -> f, n, h
+&n -> m
+&m -> f
+%f -> col
+%h -> g, k
+&k -> j
+&j -> g
+%g -> col
+&col -> rx
+";
+
+    assert_eq!(day20_2(input), "2");
+}
+
+#[test]
+#[should_panic(expected = "the high pulse of f outlasts press 1")]
+fn test_feeder_staying_high() {
+    day20_2("broadcaster -> f\n%f -> col\n&col -> rx\n");
+}
@@ -0,0 +1,133 @@
+use std::collections::HashSet;
+
+use itertools::Itertools;
+use nom::{
+    bytes::complete::tag,
+    sequence::{preceded, separated_pair, tuple},
+    IResult, Parser,
+};
+
+use crate::{
+    parse::{lines, parse_input, signed},
+    vector::Vec3,
+    voxel::{Cuboid, VoxelMap},
+};
+
+fn position(input: &str) -> IResult<&str, Vec3<i64>> {
+    let (input, (x, y, z)) = tuple((
+        signed,
+        preceded(tag(","), signed),
+        preceded(tag(","), signed),
+    ))
+    .parse(input)?;
+
+    Ok((input, Vec3(x, y, z)))
+}
+
+fn brick(input: &str) -> IResult<&str, Cuboid> {
+    let (input, (a, b)) = separated_pair(position, tag("~"), position).parse(input)?;
+
+    Ok((input, Cuboid::new(a, b)))
+}
+
+#[test]
+fn test_brick() {
+    assert_eq!(
+        brick("0,0,2~2,0,2").unwrap().1,
+        Cuboid::new(Vec3(0, 0, 2), Vec3(2, 0, 2))
+    );
+}
+
+/// The bricks after they have all fallen down, lowest first, together with the
+/// indices of the bricks each one rests on.
+fn settle(mut bricks: Vec<Cuboid>) -> (Vec<Cuboid>, Vec<HashSet<usize>>) {
+    bricks.sort_by_key(|brick| brick.min.2);
+
+    let mut stack = VoxelMap::new();
+    let mut supported_by = vec![];
+
+    for (index, brick) in bricks.iter_mut().enumerate() {
+        let tops = brick
+            .footprint()
+            .filter_map(|xy| stack.top(&xy).map(|(z, &below)| (z, below)))
+            .collect_vec();
+
+        // the ground is at z = 0
+        let rest = tops.iter().map(|(z, _)| *z).max().unwrap_or(0);
+
+        *brick = brick.translate(Vec3(0, 0, rest + 1 - brick.min.2));
+        stack.insert_cuboid(brick, index);
+
+        supported_by.push(
+            tops.into_iter()
+                .filter(|(z, _)| *z == rest)
+                .map(|(_, below)| below)
+                .collect(),
+        );
+    }
+
+    (bricks, supported_by)
+}
+
+/// The bricks that would fall if brick `removed` was disintegrated.
+fn chain_reaction(supported_by: &[HashSet<usize>], removed: usize) -> usize {
+    let mut fallen = HashSet::from([removed]);
+
+    // a brick only rests on bricks that were settled before it
+    for (index, below) in supported_by.iter().enumerate().skip(removed + 1) {
+        if !below.is_empty() && below.is_subset(&fallen) {
+            fallen.insert(index);
+        }
+    }
+
+    fallen.len() - 1
+}
+
+fn safe_to_disintegrate(supported_by: &[HashSet<usize>]) -> usize {
+    (0..supported_by.len())
+        .filter(|index| {
+            supported_by
+                .iter()
+                .all(|below| below.len() != 1 || !below.contains(index))
+        })
+        .count()
+}
+
+#[test]
+fn test_example() {
+    let input = "1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9
+";
+
+    let (bricks, supported_by) = settle(parse_input(lines(brick), input));
+
+    assert_eq!(bricks.last().unwrap().min.2, 5);
+    assert_eq!(safe_to_disintegrate(&supported_by), 5);
+    assert_eq!(
+        (0..bricks.len())
+            .map(|index| chain_reaction(&supported_by, index))
+            .sum::<usize>(),
+        7
+    );
+}
+
+pub fn day22_1(input: &str) -> String {
+    let (_, supported_by) = settle(parse_input(lines(brick), input));
+
+    safe_to_disintegrate(&supported_by).to_string()
+}
+
+pub fn day22_2(input: &str) -> String {
+    let (bricks, supported_by) = settle(parse_input(lines(brick), input));
+
+    let fallen: usize = (0..bricks.len())
+        .map(|index| chain_reaction(&supported_by, index))
+        .sum();
+
+    fallen.to_string()
+}
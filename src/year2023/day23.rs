@@ -0,0 +1,203 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+
+use crate::{
+    grid::{Grid, GridBounds, GridGet},
+    Vec2,
+};
+
+const DIRECTIONS: [Vec2<i64>; 4] = [Vec2(1, 0), Vec2(-1, 0), Vec2(0, 1), Vec2(0, -1)];
+
+impl Grid {
+    /// Tiles a hiker can step to from `pos`. On slopes the hiker can only go
+    /// downhill unless `slippery` is false.
+    fn trail_neighbours(&self, pos: &Vec2<i64>, slippery: bool) -> Vec<Vec2<i64>> {
+        let directions = match (slippery, self.get(pos)) {
+            (true, '^') => vec![Vec2(0, -1)],
+            (true, '>') => vec![Vec2(1, 0)],
+            (true, 'v') => vec![Vec2(0, 1)],
+            (true, '<') => vec![Vec2(-1, 0)],
+            _ => DIRECTIONS.to_vec(),
+        };
+
+        directions
+            .into_iter()
+            .map(|dir| *pos + dir)
+            .filter(|pos| self.in_bounds(pos) && self.get(pos) != '#')
+            .collect()
+    }
+
+    /// The open tile in row `y`.
+    fn gap(&self, y: i64) -> Vec2<i64> {
+        (0..self.width as i64)
+            .map(|x| Vec2(x, y))
+            .find(|pos| self.get(pos) == '.')
+            .unwrap()
+    }
+}
+
+/// The trail map compressed to the start, the end and every fork in between. Each
+/// node lists the nodes it leads to and the number of steps to get there.
+struct Trails {
+    edges: Vec<Vec<(usize, usize)>>,
+    start: usize,
+    end: usize,
+}
+
+impl Trails {
+    fn new(grid: &Grid, slippery: bool) -> Trails {
+        let start = grid.gap(0);
+        let end = grid.gap(grid.height as i64 - 1);
+
+        let junctions = (0..grid.height as i64)
+            .cartesian_product(0..grid.width as i64)
+            .map(|(y, x)| Vec2(x, y))
+            .filter(|pos| {
+                *pos == start
+                    || *pos == end
+                    || (grid.get(pos) != '#' && grid.trail_neighbours(pos, false).len() > 2)
+            })
+            .enumerate()
+            .map(|(index, pos)| (pos, index))
+            .collect::<HashMap<_, _>>();
+
+        // longest_from keeps the visited nodes in a u64
+        assert!(
+            junctions.len() <= 64,
+            "{} forks, but at most 64 are supported",
+            junctions.len()
+        );
+
+        let mut edges = vec![vec![]; junctions.len()];
+
+        for (junction, &index) in &junctions {
+            for first in grid.trail_neighbours(junction, slippery) {
+                let mut previous = *junction;
+                let mut pos = first;
+                let mut steps = 1;
+
+                // follow the trail until it reaches the next fork or a dead end
+                while !junctions.contains_key(&pos) {
+                    let Some(next) = grid
+                        .trail_neighbours(&pos, slippery)
+                        .into_iter()
+                        .find(|next| *next != previous)
+                    else {
+                        break;
+                    };
+
+                    previous = pos;
+                    pos = next;
+                    steps += 1;
+                }
+
+                if let Some(&other) = junctions.get(&pos) {
+                    edges[index].push((other, steps));
+                }
+            }
+        }
+
+        Trails {
+            edges,
+            start: junctions[&start],
+            end: junctions[&end],
+        }
+    }
+
+    fn longest_hike(&self) -> Option<usize> {
+        let into_end = (0..self.edges.len())
+            .filter(|&node| self.edges[node].iter().any(|&(next, _)| next == self.end))
+            .collect_vec();
+
+        // a single fork before the end has to go to the end, otherwise the path to
+        // the end would be cut off. With more than one every path has to be tried.
+        let [last] = into_end[..] else {
+            return self.longest_from(self.start, self.end, 1 << self.start);
+        };
+        let last_steps = self.edges[last]
+            .iter()
+            .filter(|&&(next, _)| next == self.end)
+            .map(|&(_, steps)| steps)
+            .max()?;
+
+        self.longest_from(self.start, last, 1 << self.start)
+            .map(|steps| steps + last_steps)
+    }
+
+    fn longest_from(&self, node: usize, last: usize, visited: u64) -> Option<usize> {
+        if node == last {
+            return Some(0);
+        }
+
+        self.edges[node]
+            .iter()
+            .filter(|&&(next, _)| visited & (1 << next) == 0)
+            .filter_map(|&(next, steps)| {
+                self.longest_from(next, last, visited | (1 << next))
+                    .map(|rest| rest + steps)
+            })
+            .max()
+    }
+}
+
+#[test]
+fn test_longest_hike() {
+    let input = "#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#
+";
+    let grid = Grid::new(input);
+
+    assert_eq!(Trails::new(&grid, true).longest_hike(), Some(94));
+    assert_eq!(Trails::new(&grid, false).longest_hike(), Some(154));
+}
+
+#[test]
+fn test_several_forks_to_the_end() {
+    // 1 and 2 both lead to the end, and the longest hike is 0, 1, 2, 3
+    let trails = Trails {
+        edges: vec![
+            vec![(1, 1), (2, 1)],
+            vec![(2, 1), (3, 1)],
+            vec![(1, 1), (3, 10)],
+            vec![],
+        ],
+        start: 0,
+        end: 3,
+    };
+
+    assert_eq!(trails.longest_hike(), Some(12));
+}
+
+pub fn day23_1(input: &str) -> String {
+    let grid = Grid::new(input);
+
+    Trails::new(&grid, true).longest_hike().unwrap().to_string()
+}
+
+pub fn day23_2(input: &str) -> String {
+    let grid = Grid::new(input);
+
+    Trails::new(&grid, false).longest_hike().unwrap().to_string()
+}
@@ -0,0 +1,175 @@
+use itertools::Itertools;
+use nom::{
+    bytes::complete::tag,
+    character::complete::space0,
+    sequence::{delimited, preceded, separated_pair, tuple},
+    IResult, Parser,
+};
+use num::{rational::Ratio, BigRational, Zero};
+
+use crate::{
+    math::linear::{big, solve},
+    parse::{lines, parse_input, signed},
+    vector::Vec3,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Hailstone {
+    position: Vec3<i64>,
+    velocity: Vec3<i64>,
+}
+
+fn vector(input: &str) -> IResult<&str, Vec3<i64>> {
+    let comma = || delimited(space0, tag(","), space0);
+
+    let (input, (x, y, z)) = tuple((
+        preceded(space0, signed),
+        preceded(comma(), signed),
+        preceded(comma(), signed),
+    ))
+    .parse(input)?;
+
+    Ok((input, Vec3(x, y, z)))
+}
+
+fn hailstone(input: &str) -> IResult<&str, Hailstone> {
+    let (input, (position, velocity)) =
+        separated_pair(vector, delimited(space0, tag("@"), space0), vector).parse(input)?;
+
+    Ok((input, Hailstone { position, velocity }))
+}
+
+#[test]
+fn test_hailstone() {
+    assert_eq!(
+        hailstone("19, 13, 30 @ -2,  1, -2").unwrap().1,
+        Hailstone {
+            position: Vec3(19, 13, 30),
+            velocity: Vec3(-2, 1, -2),
+        }
+    );
+}
+
+/// Where the paths of two hailstones cross in the xy plane, if they do so in the
+/// future of both.
+fn crossing(a: &Hailstone, b: &Hailstone) -> Option<(Ratio<i128>, Ratio<i128>)> {
+    let (p, v) = (a.position.xy(), a.velocity.xy());
+    let (q, w) = (b.position.xy(), b.velocity.xy());
+
+    let det = v.0 as i128 * w.1 as i128 - v.1 as i128 * w.0 as i128;
+    if det == 0 {
+        return None;
+    }
+
+    let d = q - p;
+    let t = Ratio::new(d.0 as i128 * w.1 as i128 - d.1 as i128 * w.0 as i128, det);
+    let s = Ratio::new(d.0 as i128 * v.1 as i128 - d.1 as i128 * v.0 as i128, det);
+
+    if t < Ratio::zero() || s < Ratio::zero() {
+        return None;
+    }
+
+    Some((t * v.0 as i128 + p.0 as i128, t * v.1 as i128 + p.1 as i128))
+}
+
+fn crossings_in_area(hailstones: &[Hailstone], min: i128, max: i128) -> usize {
+    let area = Ratio::from(min)..=Ratio::from(max);
+
+    hailstones
+        .iter()
+        .tuple_combinations()
+        .filter_map(|(a, b)| crossing(a, b))
+        .filter(|(x, y)| area.contains(x) && area.contains(y))
+        .count()
+}
+
+/// `a × b` as a matrix acting on `b`.
+fn cross_matrix(a: Vec3<i64>) -> [[i64; 3]; 3] {
+    [[0, -a.2, a.1], [a.2, 0, -a.0], [-a.1, a.0, 0]]
+}
+
+fn cross(a: Vec3<i64>, b: Vec3<i64>) -> Vec3<i128> {
+    let (a, b) = (
+        Vec3(a.0 as i128, a.1 as i128, a.2 as i128),
+        Vec3(b.0 as i128, b.1 as i128, b.2 as i128),
+    );
+
+    Vec3(
+        a.1 * b.2 - a.2 * b.1,
+        a.2 * b.0 - a.0 * b.2,
+        a.0 * b.1 - a.1 * b.0,
+    )
+}
+
+/// The position of a rock that hits every hailstone when thrown with the right
+/// velocity. For the rock `(p, v)` and any hailstone `(pi, vi)` the two are on a
+/// collision course exactly when `(p - pi) × (v - vi) = 0`. The `p × v` term is the
+/// same for every hailstone, so subtracting two of these equations leaves
+/// `p × (vj - vi) + (pj - pi) × v = pj × vj - pi × vi`, which is linear. Two pairs
+/// give the six equations needed for `p` and `v`.
+fn rock(hailstones: &[Hailstone]) -> Option<Vec3<BigRational>> {
+    hailstones
+        .iter()
+        .tuple_combinations()
+        .find_map(|(a, b, c)| {
+            let mut matrix = vec![];
+            let mut rhs = vec![];
+
+            for other in [b, c] {
+                // p × d is -(d × p), while e × v is already a matrix acting on v
+                let d = cross_matrix(other.velocity - a.velocity);
+                let e = cross_matrix(other.position - a.position);
+                let constant =
+                    cross(other.position, other.velocity) - cross(a.position, a.velocity);
+
+                for row in 0..3 {
+                    matrix.push(
+                        d[row]
+                            .iter()
+                            .map(|x| big(-x))
+                            .chain(e[row].iter().map(|x| big(*x)))
+                            .collect_vec(),
+                    );
+                }
+
+                rhs.extend(
+                    [constant.0, constant.1, constant.2]
+                        .map(|x| BigRational::from_integer(x.into())),
+                );
+            }
+
+            let solution = solve(matrix, rhs)?;
+            let (x, y, z) = solution.into_iter().take(3).collect_tuple()?;
+
+            Some(Vec3(x, y, z))
+        })
+}
+
+#[test]
+fn test_example() {
+    let input = "19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3
+";
+    let hailstones = parse_input(lines(hailstone), input);
+
+    assert_eq!(crossings_in_area(&hailstones, 7, 27), 2);
+
+    let rock = rock(&hailstones).unwrap();
+    assert_eq!(rock, Vec3(big(24), big(13), big(10)));
+}
+
+pub fn day24_1(input: &str) -> String {
+    let hailstones = parse_input(lines(hailstone), input);
+
+    crossings_in_area(&hailstones, 200000000000000, 400000000000000).to_string()
+}
+
+pub fn day24_2(input: &str) -> String {
+    let hailstones = parse_input(lines(hailstone), input);
+
+    let rock = rock(&hailstones).unwrap();
+    (rock.0 + rock.1 + rock.2).to_integer().to_string()
+}
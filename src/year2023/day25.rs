@@ -0,0 +1,70 @@
+use nom::{
+    bytes::complete::tag,
+    character::complete::{alpha1, space1},
+    multi::separated_list1,
+    sequence::separated_pair,
+    IResult, Parser,
+};
+
+use crate::{
+    graph::Graph,
+    parse::{lines, parse_input},
+};
+
+fn connections(input: &str) -> IResult<&str, (&str, Vec<&str>)> {
+    separated_pair(alpha1, tag(": "), separated_list1(space1, alpha1)).parse(input)
+}
+
+/// The sizes of the two groups left after cutting the three wires between them.
+/// Panics if the fewest wires that split the components aren't three.
+fn split_groups(wiring: &[(&str, Vec<&str>)]) -> (usize, usize) {
+    let mut graph = Graph::undirected();
+
+    for (component, others) in wiring {
+        for other in others {
+            graph.add_edge(component, other);
+        }
+    }
+
+    let (cut, group) = graph
+        .min_cut()
+        .expect("there should be at least two components");
+    assert_eq!(cut, 3, "the groups should be split by cutting three wires");
+
+    (group.len(), graph.len() - group.len())
+}
+
+#[test]
+fn test_split_groups() {
+    let input = "jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr
+";
+    let wiring = parse_input(lines(connections), input);
+
+    let (a, b) = split_groups(&wiring);
+    assert_eq!(a * b, 54);
+}
+
+#[test]
+#[should_panic(expected = "three wires")]
+fn test_split_groups_by_one_wire() {
+    split_groups(&parse_input(lines(connections), "a: b\nb: c\n"));
+}
+
+pub fn day25_1(input: &str) -> String {
+    let wiring = parse_input(lines(connections), input);
+
+    let (a, b) = split_groups(&wiring);
+    (a * b).to_string()
+}
@@ -0,0 +1,186 @@
+use std::{collections::HashMap, ops::Range};
+
+/// A number and the columns its digits cover.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Number {
+    value: usize,
+    row: usize,
+    columns: Range<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Symbol {
+    symbol: char,
+    row: usize,
+    column: usize,
+}
+
+/// The numbers and symbols of an engine schematic, and which numbers are next to
+/// which symbols, diagonals included.
+#[derive(Debug)]
+struct Schematic {
+    numbers: Vec<Number>,
+    symbols: Vec<Symbol>,
+    /// For every symbol, the indices of the numbers next to it.
+    adjacent: Vec<Vec<usize>>,
+}
+
+impl Schematic {
+    fn new(input: &str) -> Schematic {
+        let mut numbers = vec![];
+        let mut symbols = vec![];
+
+        for (row, line) in input.lines().enumerate() {
+            let mut number: Option<Number> = None;
+
+            for (column, c) in line.chars().enumerate() {
+                if let Some(digit) = c.to_digit(10) {
+                    let number = number.get_or_insert(Number {
+                        value: 0,
+                        row,
+                        columns: column..column,
+                    });
+                    number.value = number.value * 10 + digit as usize;
+                    number.columns.end = column + 1;
+                    continue;
+                }
+
+                numbers.extend(number.take());
+
+                if c != '.' {
+                    symbols.push(Symbol {
+                        symbol: c,
+                        row,
+                        column,
+                    });
+                }
+            }
+
+            numbers.extend(number);
+        }
+
+        let positions: HashMap<_, _> = symbols
+            .iter()
+            .enumerate()
+            .map(|(i, symbol)| ((symbol.row, symbol.column), i))
+            .collect();
+
+        let mut adjacent = vec![vec![]; symbols.len()];
+
+        for (i, number) in numbers.iter().enumerate() {
+            for row in number.row.saturating_sub(1)..=number.row + 1 {
+                for column in number.columns.start.saturating_sub(1)..=number.columns.end {
+                    if let Some(&symbol) = positions.get(&(row, column)) {
+                        adjacent[symbol].push(i);
+                    }
+                }
+            }
+        }
+
+        Schematic {
+            numbers,
+            symbols,
+            adjacent,
+        }
+    }
+
+    /// Numbers next to at least one symbol. Each number counts once however many
+    /// symbols it touches, and equal numbers count separately.
+    fn part_numbers(&self) -> impl Iterator<Item = &Number> {
+        let mut is_part = vec![false; self.numbers.len()];
+        for &number in self.adjacent.iter().flatten() {
+            is_part[number] = true;
+        }
+
+        self.numbers
+            .iter()
+            .zip(is_part)
+            .filter(|(_, is_part)| *is_part)
+            .map(|(number, _)| number)
+    }
+
+    /// The product of the two numbers next to every `*` that has exactly two.
+    fn gear_ratios(&self) -> impl Iterator<Item = usize> + '_ {
+        self.symbols
+            .iter()
+            .zip(&self.adjacent)
+            .filter(|(symbol, numbers)| symbol.symbol == '*' && numbers.len() == 2)
+            .map(|(_, numbers)| {
+                numbers
+                    .iter()
+                    .map(|&number| self.numbers[number].value)
+                    .product()
+            })
+    }
+}
+
+#[test]
+fn test_example() {
+    let schematic = Schematic::new(
+        "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..",
+    );
+
+    assert_eq!(
+        schematic.numbers[0],
+        Number {
+            value: 467,
+            row: 0,
+            columns: 0..3
+        }
+    );
+    assert_eq!(schematic.adjacent[0], vec![0, 2]);
+    assert_eq!(
+        schematic
+            .part_numbers()
+            .map(|number| number.value)
+            .sum::<usize>(),
+        4361
+    );
+    assert_eq!(schematic.gear_ratios().sum::<usize>(), 467835);
+}
+
+#[test]
+fn test_equal_numbers_and_borders() {
+    // the same number twice next to one symbol, and symbols in the corners
+    let schematic = Schematic::new(
+        "*12.12
+5.....
+12*12.
+.....#",
+    );
+
+    assert_eq!(schematic.symbols.len(), 3);
+    assert_eq!(
+        schematic
+            .part_numbers()
+            .map(|number| number.value)
+            .collect::<Vec<_>>(),
+        vec![12, 5, 12, 12]
+    );
+    assert_eq!(schematic.gear_ratios().collect::<Vec<_>>(), vec![60, 144]);
+    assert_eq!(schematic.adjacent[2], vec![4]);
+}
+
+pub fn day3_1(input: &str) -> String {
+    let code: usize = Schematic::new(input)
+        .part_numbers()
+        .map(|number| number.value)
+        .sum();
+
+    code.to_string()
+}
+
+pub fn day3_2(input: &str) -> String {
+    let code: usize = Schematic::new(input).gear_ratios().sum();
+
+    code.to_string()
+}
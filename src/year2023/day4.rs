@@ -0,0 +1,101 @@
+use nom::{
+    bytes::complete::tag,
+    character::complete::space1,
+    sequence::{preceded, separated_pair, tuple},
+    IResult,
+};
+
+use crate::{
+    memo::Memo,
+    parse::{key_value, lines, numbers, parse_input, unsigned},
+};
+
+#[derive(Debug, Clone)]
+struct Card {
+    id: usize,
+    winning_numbers: Vec<usize>,
+    my_numbers: Vec<usize>,
+}
+
+fn card(input: &str) -> IResult<&str, Card> {
+    let (input, (id, (winning_numbers, my_numbers))) = key_value(
+        preceded(tuple((tag("Card"), space1)), unsigned),
+        separated_pair(numbers, tag(" |"), numbers),
+    )(input)?;
+
+    Ok((
+        input,
+        Card {
+            id,
+            winning_numbers,
+            my_numbers,
+        },
+    ))
+}
+
+pub fn day4_1(input: &str) -> String {
+    let lottery: Vec<_> = parse_input(lines(card), input)
+        .into_iter()
+        .map(|card| {
+            card.my_numbers.iter().fold(0, |acc, number| {
+                if card.winning_numbers.contains(number) {
+                    if acc == 0 {
+                        return 1;
+                    } else {
+                        return acc << 1;
+                    }
+                }
+
+                acc
+            })
+        })
+        .collect();
+
+    let code: i32 = lottery.iter().sum();
+
+    code.to_string()
+}
+
+#[derive(Clone)]
+struct CardPrecalculated {
+    card: Card,
+    calculated: usize,
+}
+
+/// The number of cards ending up in the pile because of `card`, including itself.
+fn scratchcards(
+    memo: &mut Memo<usize, usize>,
+    card: &CardPrecalculated,
+    lookup: &[CardPrecalculated],
+) -> usize {
+    memo.get_or_compute(card.card.id, |memo| {
+        let extra_cards = &lookup[card.card.id..(card.card.id + card.calculated)];
+
+        1 + extra_cards
+            .iter()
+            .map(|card| scratchcards(memo, card, lookup))
+            .sum::<usize>()
+    })
+}
+
+pub fn day4_2(input: &str) -> String {
+    let lottery: Vec<_> = parse_input(lines(card), input)
+        .into_iter()
+        .map(|card| CardPrecalculated {
+            card: card.clone(),
+            calculated: card
+                .my_numbers
+                .iter()
+                .filter(|number| card.winning_numbers.contains(number))
+                .count(),
+        })
+        .collect();
+
+    let mut memo = Memo::new();
+    let scratchcards: usize = lottery
+        .iter()
+        .map(|card| scratchcards(&mut memo, card, &lottery))
+        .sum();
+
+    scratchcards.to_string()
+}
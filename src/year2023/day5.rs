@@ -0,0 +1,167 @@
+use nom::{
+    bytes::complete::{tag, take_until},
+    character::complete::space1,
+    multi::{many1, separated_list1},
+    sequence::{preceded, tuple},
+    IResult,
+};
+
+use crate::{
+    intervals::{Interval, IntervalMap, IntervalSet},
+    parse::{key_value, newlines, numbers, parse_input, unsigned},
+};
+
+#[derive(Debug, Clone)]
+struct Mapping {
+    dest: usize,
+    source: usize,
+    range: usize,
+}
+
+fn mapping(input: &str) -> IResult<&str, Mapping> {
+    let (input, (dest, source, range)) = tuple((
+        unsigned,
+        preceded(space1, unsigned),
+        preceded(space1, unsigned),
+    ))(input)?;
+
+    Ok((
+        input,
+        Mapping {
+            dest,
+            source,
+            range,
+        },
+    ))
+}
+
+fn map(input: &str) -> IResult<&str, Vec<Mapping>> {
+    let (input, _) = newlines(input)?;
+    let (input, _) = take_until(" map:\n")(input)?;
+    let (input, _) = tag(" map:\n")(input)?;
+
+    separated_list1(many1(tag("\n")), mapping)(input)
+}
+
+#[derive(Debug, Clone)]
+struct Mapper {
+    seeds: Vec<usize>,
+    soil: Vec<Mapping>,
+    fertilizer: Vec<Mapping>,
+    water: Vec<Mapping>,
+    light: Vec<Mapping>,
+    temperature: Vec<Mapping>,
+    humidity: Vec<Mapping>,
+    location: Vec<Mapping>,
+}
+
+fn mapper(input: &str) -> IResult<&str, Mapper> {
+    let (input, (_, seeds)) = key_value(tag("seeds"), numbers)(input)?;
+    let (input, _) = newlines(input)?;
+
+    let (input, soil) = map(input)?;
+    let (input, fertilizer) = map(input)?;
+    let (input, water) = map(input)?;
+    let (input, light) = map(input)?;
+    let (input, temperature) = map(input)?;
+    let (input, humidity) = map(input)?;
+    let (input, location) = map(input)?;
+
+    let (input, _) = newlines(input)?;
+
+    Ok((
+        input,
+        Mapper {
+            seeds,
+            soil,
+            fertilizer,
+            water,
+            light,
+            temperature,
+            humidity,
+            location,
+        },
+    ))
+}
+
+fn map_seed(seeds: &Vec<usize>, mappings: &Vec<Mapping>) -> Vec<usize> {
+    seeds
+        .iter()
+        .map(|seed| {
+            if let Some(mapping) = mappings
+                .iter()
+                .find(|mapping| (mapping.source..(mapping.source + mapping.range)).contains(seed))
+            {
+                seed - mapping.source + mapping.dest
+            } else {
+                *seed
+            }
+        })
+        .collect()
+}
+
+pub fn day5_1(input: &str) -> String {
+    let mapper = parse_input(mapper, input);
+
+    let soil = map_seed(&mapper.seeds, &mapper.soil);
+    let fertilizer = map_seed(&soil, &mapper.fertilizer);
+    let water = map_seed(&fertilizer, &mapper.water);
+    let light = map_seed(&water, &mapper.light);
+    let temperature = map_seed(&light, &mapper.temperature);
+    let humidity = map_seed(&temperature, &mapper.humidity);
+    let location = map_seed(&humidity, &mapper.location);
+
+    let min_location = location.iter().min().unwrap();
+
+    min_location.to_string()
+}
+
+impl Mapping {
+    fn interval(&self) -> Interval {
+        Interval::new(self.source as i64, (self.source + self.range) as i64)
+    }
+
+    fn offset(&self) -> i64 {
+        self.dest as i64 - self.source as i64
+    }
+}
+
+fn interval_map(mappings: &[Mapping]) -> IntervalMap {
+    let mut map = IntervalMap::new();
+
+    for mapping in mappings {
+        map.insert(mapping.interval(), mapping.offset());
+    }
+
+    map
+}
+
+fn map_seed_range(seed_range: &IntervalSet, mapper: &Mapper) -> IntervalSet {
+    [
+        &mapper.soil,
+        &mapper.fertilizer,
+        &mapper.water,
+        &mapper.light,
+        &mapper.temperature,
+        &mapper.humidity,
+        &mapper.location,
+    ]
+    .into_iter()
+    .fold(seed_range.clone(), |ranges, mappings| {
+        interval_map(mappings).map_set(&ranges)
+    })
+}
+
+pub fn day5_2(input: &str) -> String {
+    let mapper = parse_input(mapper, input);
+
+    let seed_range: IntervalSet = mapper
+        .seeds
+        .chunks(2)
+        .map(|chunk| Interval::new(chunk[0] as i64, (chunk[0] + chunk[1]) as i64))
+        .collect();
+
+    let min_location = map_seed_range(&seed_range, &mapper).min().unwrap();
+
+    min_location.to_string()
+}
@@ -0,0 +1,75 @@
+use nom::{
+    bytes::complete::tag,
+    character::complete::{digit1, space1},
+    multi::separated_list1,
+    IResult,
+};
+
+use crate::parse::{key_value, newlines, numbers, parse_input};
+
+fn boat_race(input: &str) -> IResult<&str, (Vec<usize>, Vec<usize>)> {
+    let (input, (_, time)) = key_value(tag("Time"), numbers)(input)?;
+    let (input, _) = newlines(input)?;
+
+    let (input, (_, distance)) = key_value(tag("Distance"), numbers)(input)?;
+    let (input, _) = newlines(input)?;
+
+    Ok((input, (time, distance)))
+}
+
+pub fn day6_1(input: &str) -> String {
+    let (time, distance) = parse_input(boat_race, input);
+
+    let code: usize = time
+        .iter()
+        .zip(distance.iter())
+        .map(|(time, distance)| {
+            (0..*time)
+                .filter(|hold| hold * (time - hold) > *distance)
+                .count()
+        })
+        .product();
+
+    code.to_string()
+}
+
+fn boat_race_ignore_space(input: &str) -> IResult<&str, (Vec<usize>, Vec<usize>)> {
+    let (input, (_, time)) = key_value(tag("Time"), separated_list1(space1, digit1))(input)?;
+
+    let time: usize = time
+        .iter()
+        .map(|x| x.to_string())
+        .collect::<String>()
+        .parse()
+        .unwrap();
+
+    let (input, _) = newlines(input)?;
+
+    let (input, (_, distance)) =
+        key_value(tag("Distance"), separated_list1(space1, digit1))(input)?;
+    let distance: usize = distance
+        .iter()
+        .map(|x| x.to_string())
+        .collect::<String>()
+        .parse()
+        .unwrap();
+    let (input, _) = newlines(input)?;
+
+    Ok((input, (vec![time], vec![distance])))
+}
+
+pub fn day6_2(input: &str) -> String {
+    let (time, distance) = parse_input(boat_race_ignore_space, input);
+
+    let code: usize = time
+        .iter()
+        .zip(distance.iter())
+        .map(|(time, distance)| {
+            (0..*time)
+                .filter(|hold| hold * (time - hold) > *distance)
+                .count()
+        })
+        .product();
+
+    code.to_string()
+}
@@ -1,20 +1,17 @@
 use itertools::Itertools;
-use std::{cmp::Ordering, collections::BTreeMap};
+use std::cmp::Ordering;
 
 use nom::{
-    bytes::complete::{tag, take, take_while1},
-    combinator::map_res,
-    multi::many0,
-    sequence::tuple,
+    bytes::complete::take,
+    character::complete::space1,
+    sequence::{preceded, tuple},
     IResult,
 };
 
-use crate::read_lines;
-
-fn digit(input: &str) -> IResult<&str, usize> {
-    let (input, _) = many0(tag(" "))(input)?;
-    map_res(take_while1(char::is_numeric), |x: &str| x.parse())(input)
-}
+use crate::{
+    aoc_iteratorutils::AdventOfCodeIteratorUtils,
+    parse::{lines, parse_input, unsigned},
+};
 
 fn card(input: &str) -> IResult<&str, usize> {
     let (input, card) = take(1_usize)(input)?;
@@ -33,7 +30,7 @@ fn card(input: &str) -> IResult<&str, usize> {
 
 fn hand(input: &str) -> IResult<&str, (HandTuple, usize)> {
     let (input, hand) = tuple((card, card, card, card, card))(input)?;
-    let (input, bid) = digit(input)?;
+    let (input, bid) = preceded(space1, unsigned)(input)?;
 
     Ok((input, (hand, bid)))
 }
@@ -49,17 +46,6 @@ enum HandType {
     FiveOfAKind,
 }
 
-fn group_by_label<A, I>(v: I) -> BTreeMap<A, usize>
-where
-    A: Ord,
-    I: IntoIterator<Item = A>,
-{
-    v.into_iter().fold(BTreeMap::new(), |mut acc, a| {
-        *acc.entry(a).or_default() += 1;
-        acc
-    })
-}
-
 type HandTuple = (usize, usize, usize, usize, usize);
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Ord)]
@@ -78,7 +64,7 @@ impl PartialOrd for Hand {
 fn hand_type(hand: &HandTuple) -> HandType {
     let (a, b, c, d, e) = hand;
     let sorted_hand = [a, b, c, d, e].to_vec();
-    let labels = group_by_label(sorted_hand);
+    let labels = AdventOfCodeIteratorUtils::counts(sorted_hand.into_iter());
     let mut values: Vec<usize> = labels.into_values().collect();
     values.sort();
 
@@ -121,17 +107,16 @@ fn hand_type(hand: &HandTuple) -> HandType {
     }
 }
 
-pub fn day7_1() {
-    let winnings: usize = read_lines("inputs/day7.txt")
-        .map(|line| line.unwrap())
-        .map(|line| hand(&line).unwrap().1)
+pub fn day7_1(input: &str) -> String {
+    let winnings: usize = parse_input(lines(hand), input)
+        .into_iter()
         .map(|(hand, bid)| Hand(hand, hand_type(&hand), bid))
         .sorted()
         .enumerate()
         .map(|(rank, hand)| hand.2 * (rank + 1))
         .sum();
 
-    dbg!(winnings);
+    winnings.to_string()
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Ord)]
@@ -196,7 +181,7 @@ impl PartialOrd for HandWithJoker {
 fn hand_type_with_joker(hand: &HandTuple) -> HandType {
     let (a, b, c, d, e) = hand;
     let hand_list = [a, b, c, d, e].to_vec();
-    let labels = group_by_label(hand_list);
+    let labels = AdventOfCodeIteratorUtils::counts(hand_list.into_iter());
 
     if let Some(jokers) = labels.get(&11).cloned() {
         let mut hands = vec![];
@@ -236,10 +221,9 @@ fn hand_type_with_joker(hand: &HandTuple) -> HandType {
     hand_type(hand)
 }
 
-pub fn day7_2() {
-    let winnings: usize = read_lines("inputs/day7.txt")
-        .map(|line| line.unwrap())
-        .map(|line| hand(&line).unwrap().1)
+pub fn day7_2(input: &str) -> String {
+    let winnings: usize = parse_input(lines(hand), input)
+        .into_iter()
         .map(|(hand, bid)| HandWithJoker(hand, hand_type_with_joker(&hand), bid))
         .sorted()
         .enumerate()
@@ -247,5 +231,5 @@ pub fn day7_2() {
         .map(|(rank, hand)| hand.2 * (rank + 1))
         .sum();
 
-    dbg!(winnings);
+    winnings.to_string()
 }
@@ -0,0 +1,175 @@
+use itertools::Itertools;
+use std::collections::HashMap;
+
+use nom::{
+    bytes::complete::{tag, take_while1},
+    multi::{many0, separated_list1},
+    sequence::tuple,
+    IResult,
+};
+
+use crate::{
+    cycle::detect_cycle,
+    graph::Graph,
+    math::{first_common_step, Walker},
+    parse::{newlines, parse_input},
+};
+
+fn node(input: &str) -> IResult<&str, (&str, (&str, &str))> {
+    let (input, node) = take_while1(char::is_alphanumeric)(input)?;
+    let (input, _) = many0(tag(" = "))(input)?;
+    let (input, (_, left, _, right, _)) = tuple((
+        tag("("),
+        take_while1(char::is_alphanumeric),
+        tag(", "),
+        take_while1(char::is_alphanumeric),
+        tag(")"),
+    ))(input)?;
+
+    Ok((input, (node, (left, right))))
+}
+
+fn network(input: &str) -> IResult<&str, (&str, HashMap<&str, (&str, &str)>)> {
+    let (input, paths) = take_while1(char::is_alphabetic)(input)?;
+    let (input, _) = newlines(input)?;
+    let (input, nodes) = separated_list1(tag("\n"), node)(input)?;
+    let (input, _) = newlines(input)?;
+
+    let mut hashmap = HashMap::new();
+    for (key, value) in nodes {
+        hashmap.insert(key, value);
+    }
+
+    Ok((input, (paths, hashmap)))
+}
+
+fn take_path<'a>(
+    path: char,
+    node: &'a str,
+    nodes: &HashMap<&'a str, (&'a str, &'a str)>,
+) -> &'a str {
+    match path {
+        'R' => nodes[node].1,
+        'L' => nodes[node].0,
+        _ => panic!("Unknown path"),
+    }
+}
+
+fn network_graph(nodes: &HashMap<&str, (&str, &str)>) -> Graph {
+    let mut graph = Graph::directed();
+
+    // sorted, so node ids and the DOT output are the same on every run
+    for (node, (left, right)) in nodes.iter().sorted_by_key(|(node, _)| **node) {
+        graph.add_edge(node, left);
+        graph.add_edge(node, right);
+    }
+
+    graph
+}
+
+pub fn graph(input: &str) -> Graph {
+    let (_, nodes) = parse_input(network, input);
+
+    network_graph(&nodes)
+}
+
+#[test]
+fn test_graph() {
+    let input = "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)
+";
+    let graph = graph(input);
+
+    assert_eq!(
+        (0..graph.len()).map(|id| graph.name(id)).collect_vec(),
+        vec!["AAA", "BBB", "CCC", "DDD", "EEE", "ZZZ", "GGG"]
+    );
+}
+
+pub fn day8_1(input: &str) -> String {
+    let (paths, nodes) = parse_input(network, input);
+
+    let graph = network_graph(&nodes);
+    let reachable = graph.reachable(graph.id("AAA").unwrap());
+    assert!(reachable[graph.id("ZZZ").unwrap()], "ZZZ can't be reached");
+
+    let mut node = "AAA";
+
+    let mut steps = 0;
+
+    for path in paths.chars().cycle() {
+        node = take_path(path, node, &nodes);
+
+        steps += 1;
+
+        if node == "ZZZ" {
+            break;
+        }
+    }
+
+    steps.to_string()
+}
+
+fn take_path_owned<'a>(
+    path: char,
+    node: &'a str,
+    nodes: &HashMap<&'a str, (&'a str, &'a str)>,
+) -> String {
+    match path {
+        'R' => nodes[node].1.to_string(),
+        'L' => nodes[node].0.to_string(),
+        _ => panic!("Unknown path"),
+    }
+}
+
+pub fn day8_2(input: &str) -> String {
+    let (paths, nodes) = parse_input(network, input);
+
+    let start_nodes: Vec<String> = nodes
+        .keys()
+        .filter(|key| key.ends_with("A"))
+        .map(|x| x.to_string())
+        .collect();
+
+    let instructions = paths.chars().collect_vec();
+
+    let walkers = start_nodes
+        .into_iter()
+        .map(|starting_node| {
+            let mut hits = vec![];
+            let mut steps = 0;
+
+            let cycle = detect_cycle((starting_node, 0), |(node, index)| {
+                if node.ends_with("Z") {
+                    hits.push(steps);
+                }
+                steps += 1;
+
+                (
+                    take_path_owned(instructions[*index], node, &nodes),
+                    (index + 1) % instructions.len(),
+                )
+            });
+
+            Walker {
+                offset: cycle.start as i64,
+                period: cycle.period as i64,
+                hits: hits
+                    .into_iter()
+                    .filter(|&hit| hit < cycle.start + cycle.period)
+                    .map(|hit| hit as i64)
+                    .collect(),
+            }
+        })
+        .collect_vec();
+
+    let code = first_common_step(&walkers).unwrap();
+    code.to_string()
+}
@@ -0,0 +1,27 @@
+use crate::{
+    aoc_iteratorutils::AdventOfCodeIteratorUtils,
+    math::poly::extrapolate,
+    parse::{lines, numbers, parse_input},
+};
+
+type Day9Integer = i128;
+
+pub fn day9_1(input: &str) -> String {
+    let code = parse_input(lines(numbers::<Day9Integer>), input)
+        .into_iter()
+        .map(|history| extrapolate(&history, history.len() as Day9Integer))
+        .sum_checked()
+        .unwrap();
+
+    code.to_string()
+}
+
+pub fn day9_2(input: &str) -> String {
+    let code = parse_input(lines(numbers::<Day9Integer>), input)
+        .into_iter()
+        .map(|history| extrapolate(&history, -1))
+        .sum_checked()
+        .unwrap();
+
+    code.to_string()
+}
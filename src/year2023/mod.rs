@@ -0,0 +1,83 @@
+mod day1;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day2;
+mod day20;
+mod day21;
+mod day22;
+mod day23;
+mod day24;
+mod day25;
+mod day3;
+mod day4;
+mod day5;
+mod day6;
+mod day7;
+mod day8;
+mod day9;
+
+use crate::{Graphs, Solutions};
+
+/// Every solution of the year as `(day, part, function)`.
+pub const SOLUTIONS: Solutions = &[
+    (1, 1, day1::day1_1),
+    (1, 2, day1::day1_2),
+    (2, 1, day2::day2_1),
+    (2, 2, day2::day2_2),
+    (3, 1, day3::day3_1),
+    (3, 2, day3::day3_2),
+    (4, 1, day4::day4_1),
+    (4, 2, day4::day4_2),
+    (5, 1, day5::day5_1),
+    (5, 2, day5::day5_2),
+    (6, 1, day6::day6_1),
+    (6, 2, day6::day6_2),
+    (7, 1, day7::day7_1),
+    (7, 2, day7::day7_2),
+    (8, 1, day8::day8_1),
+    (8, 2, day8::day8_2),
+    (9, 1, day9::day9_1),
+    (9, 2, day9::day9_2),
+    (10, 1, day10::day10_1),
+    (10, 2, day10::day10_2),
+    (11, 1, day11::day11_1),
+    (11, 2, day11::day11_2),
+    (12, 1, day12::day12_1),
+    (12, 2, day12::day12_2),
+    (13, 1, day13::day13_1),
+    (13, 2, day13::day13_2),
+    (14, 1, day14::day14_1),
+    (14, 2, day14::day14_2),
+    (15, 1, day15::day15_1),
+    (15, 2, day15::day15_2),
+    (16, 1, day16::day16_1),
+    (16, 2, day16::day16_2),
+    (17, 1, day17::day17_1),
+    (17, 2, day17::day17_2),
+    (18, 1, day18::day18_1),
+    (18, 2, day18::day18_2),
+    (19, 1, day19::day19_1),
+    (19, 2, day19::day19_2),
+    (20, 1, day20::day20_1),
+    (20, 2, day20::day20_2),
+    (21, 1, day21::day21_1),
+    (21, 2, day21::day21_2),
+    (22, 1, day22::day22_1),
+    (22, 2, day22::day22_2),
+    (23, 1, day23::day23_1),
+    (23, 2, day23::day23_2),
+    (24, 1, day24::day24_1),
+    (24, 2, day24::day24_2),
+    (25, 1, day25::day25_1),
+];
+
+/// The days whose input is a graph, for `aoc dot`.
+pub const GRAPHS: Graphs = &[(8, day8::graph), (19, day19::graph), (20, day20::graph)];